@@ -0,0 +1,58 @@
+// audio/level_meter.rs
+//
+// Tracks a smoothed microphone/system RMS level while a recording is active
+// and drives the optional "auto-pause on silence" mode: when the level stays
+// below a configurable threshold for long enough, the recording is paused
+// automatically and resumed as soon as sound returns.
+
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+/// Smoothing factor for the exponential moving average applied to each new
+/// RMS sample, in `[0, 1]`. Higher favours the latest sample.
+const LEVEL_SMOOTHING: f32 = 0.2;
+
+static SMOOTHED_LEVEL: AtomicU32 = AtomicU32::new(0);
+static AUTO_PAUSE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SilenceThreshold {
+    /// Smoothed RMS level (0.0-1.0) below which audio counts as silence.
+    pub level: f32,
+    /// How long the level must stay below `level` before auto-pausing.
+    pub duration_secs: u64,
+}
+
+impl Default for SilenceThreshold {
+    fn default() -> Self {
+        Self {
+            level: 0.02,
+            duration_secs: 5,
+        }
+    }
+}
+
+/// Called from the audio capture loop with each new RMS sample.
+pub fn push_level_sample(rms: f32) {
+    let previous = f32::from_bits(SMOOTHED_LEVEL.load(Ordering::Relaxed));
+    let smoothed = previous + (rms - previous) * LEVEL_SMOOTHING;
+    SMOOTHED_LEVEL.store(smoothed.to_bits(), Ordering::Relaxed);
+}
+
+/// Current smoothed level, used to drive the tray tooltip and the
+/// auto-pause-on-silence watchdog.
+pub fn current_level() -> f32 {
+    f32::from_bits(SMOOTHED_LEVEL.load(Ordering::Relaxed))
+}
+
+pub fn set_auto_pause_enabled(enabled: bool) {
+    AUTO_PAUSE_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+pub fn is_auto_pause_enabled() -> bool {
+    AUTO_PAUSE_ENABLED.load(Ordering::SeqCst)
+}
+
+/// Reset the smoothed level at the start of a new recording session.
+pub fn reset() {
+    SMOOTHED_LEVEL.store(0, Ordering::Relaxed);
+}
@@ -0,0 +1,146 @@
+// audio/partial_stabilizer.rs
+//
+// Stabilizes interim transcription results so low-latency callers (live
+// captions) can show words as they firm up, while the durable
+// `TranscriptSegment` history still commits each word exactly once. A
+// streaming provider (see `streaming_transcription`) returns a running list
+// of items for the current utterance, each carrying a stability score that
+// increases as later audio confirms it; this module tracks how many items
+// have already been committed and only persists items once they cross the
+// configured stability threshold, so an earlier spelling/punctuation change
+// never gets "randomly discarded" after it was already shown to the user.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+use super::transcription::TranscriptUpdate;
+
+/// One word/punctuation item in a provider's running utterance result.
+#[derive(Debug, Clone)]
+pub struct TranscriptItem {
+    pub text: String,
+    /// 0.0 (just appeared) to 1.0 (will not change again).
+    pub stability: f32,
+}
+
+/// How aggressively partial results are committed as durable segments.
+/// Higher is slower to commit but avoids rewriting already-committed words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum StabilityThreshold {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl StabilityThreshold {
+    fn min_stability(self) -> f32 {
+        match self {
+            StabilityThreshold::Low => 0.4,
+            StabilityThreshold::Medium => 0.7,
+            StabilityThreshold::High => 0.9,
+        }
+    }
+}
+
+static STABILITY_THRESHOLD: Mutex<StabilityThreshold> = Mutex::new(StabilityThreshold::Medium);
+
+/// Current stability threshold, readable synchronously from the streaming
+/// provider loop.
+pub fn current_stability_threshold() -> StabilityThreshold {
+    *STABILITY_THRESHOLD.lock().unwrap()
+}
+
+/// Set the stability threshold used by every `PartialStabilizer`, chosen
+/// from Settings (recording preferences).
+#[tauri::command]
+pub async fn set_stability_threshold(threshold: StabilityThreshold) -> Result<(), String> {
+    *STABILITY_THRESHOLD.lock().unwrap() = threshold;
+    log::info!("Partial-result stability threshold set to {:?}", threshold);
+    Ok(())
+}
+
+/// Tracks commit progress for a single utterance's running item list. Dedup
+/// is keyed on `(sequence_id, item_index)`: `partial_index` marks the first
+/// item of this utterance that has not yet been committed, so re-processing
+/// the same prefix on a later update never re-commits an item.
+pub struct PartialStabilizer {
+    sequence_id: u64,
+    partial_index: usize,
+}
+
+impl PartialStabilizer {
+    pub fn new(sequence_id: u64) -> Self {
+        Self {
+            sequence_id,
+            partial_index: 0,
+        }
+    }
+
+    /// Given the provider's full running item list for this utterance,
+    /// return the partial caption text (everything up to the last stable
+    /// item) and any newly-finalized `TranscriptUpdate`s to persist. Advances
+    /// `partial_index` past the items it finalizes.
+    pub fn update(&mut self, items: &[TranscriptItem]) -> (String, Vec<TranscriptUpdate>) {
+        let threshold = current_stability_threshold().min_stability();
+
+        // The longest *stable prefix starting at `partial_index`*, not the
+        // last stable item anywhere in the list — a later item crossing the
+        // threshold must never pull an intervening unstable item (still
+        // subject to change) along with it into a permanent commit.
+        let mut stable_len = self.partial_index;
+        while stable_len < items.len() && items[stable_len].stability >= threshold {
+            stable_len += 1;
+        }
+
+        // The caption itself is not permanent, so it runs up to the last
+        // stable item even when an unstable one sits before it.
+        let partial_len = items
+            .iter()
+            .rposition(|item| item.stability >= threshold)
+            .map_or(0, |last| last + 1)
+            .max(stable_len);
+
+        let partial_text = items
+            .iter()
+            .take(partial_len)
+            .map(|item| item.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let finalized = self.commit_up_to(items, stable_len);
+        (partial_text, finalized)
+    }
+
+    /// Called when the utterance finalizes: flush every remaining item as
+    /// committed regardless of stability, then reset the cursor for the next
+    /// utterance.
+    pub fn finalize(&mut self, items: &[TranscriptItem]) -> Vec<TranscriptUpdate> {
+        let finalized = self.commit_up_to(items, items.len());
+        self.partial_index = 0;
+        finalized
+    }
+
+    fn commit_up_to(&mut self, items: &[TranscriptItem], up_to: usize) -> Vec<TranscriptUpdate> {
+        if up_to <= self.partial_index {
+            return Vec::new();
+        }
+
+        let finalized = items[self.partial_index..up_to]
+            .iter()
+            .enumerate()
+            .map(|(offset, item)| TranscriptUpdate {
+                sequence_id: self.sequence_id * 10_000 + (self.partial_index + offset) as u64,
+                text: item.text.clone(),
+                audio_start_time: 0.0,
+                audio_end_time: 0.0,
+                duration: 0.0,
+                timestamp: chrono::Local::now().to_rfc3339(),
+                confidence: item.stability,
+            })
+            .collect();
+
+        self.partial_index = up_to;
+        finalized
+    }
+}
@@ -0,0 +1,128 @@
+// audio/policy_watchdog.rs
+//
+// Enforces the "a recording must actually be transcribed" policy: if no
+// transcript activity is observed for a configurable grace period while a
+// recording is active (or the transcription task has died outright), emit a
+// `recording-policy-violation` event so the user isn't left thinking
+// transcription is still happening, and optionally auto-stop the recording
+// after a further grace window instead of letting it run unreviewed forever.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// How long transcript activity may be silent before the policy is violated.
+const ACTIVITY_GRACE: Duration = Duration::from_secs(45);
+/// How much longer we wait, once violated, before auto-stopping.
+const AUTO_STOP_GRACE: Duration = Duration::from_secs(30);
+/// How often the watchdog samples.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+static LAST_ACTIVITY: Mutex<Option<Instant>> = Mutex::new(None);
+static AUTO_STOP_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Called whenever a `transcript-update` event is received, so the watchdog
+/// knows transcription is still alive.
+pub fn mark_activity() {
+    *LAST_ACTIVITY.lock().unwrap() = Some(Instant::now());
+}
+
+/// Reset tracked activity at the start of a new recording session.
+pub fn reset() {
+    *LAST_ACTIVITY.lock().unwrap() = Some(Instant::now());
+}
+
+/// Enable or disable auto-stopping a recording whose transcription has gone
+/// silent past the grace window, instead of only ever notifying the user.
+pub fn set_auto_stop_enabled(enabled: bool) {
+    AUTO_STOP_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+pub fn is_auto_stop_enabled() -> bool {
+    AUTO_STOP_ENABLED.load(Ordering::SeqCst)
+}
+
+/// Spawn the watchdog for the lifetime of one recording session. `is_active`
+/// is polled each tick so the loop exits as soon as the recording stops;
+/// `is_paused` lets the caller report a legitimate pause, which skips the
+/// check for that tick (and resets the activity timer on the tick a pause
+/// ends) instead of counting the silence against the recording the way a
+/// genuinely dead transcription pipeline would; `transcription_alive` lets
+/// the caller report whether the `TRANSCRIPTION_TASK` handle has already
+/// finished.
+pub fn spawn<R, IsActive, IsPaused, TranscriptionAlive>(
+    app: AppHandle<R>,
+    is_active: IsActive,
+    is_paused: IsPaused,
+    transcription_alive: TranscriptionAlive,
+) -> tokio::task::JoinHandle<()>
+where
+    R: Runtime,
+    IsActive: Fn() -> bool + Send + 'static,
+    IsPaused: Fn() -> bool + Send + 'static,
+    TranscriptionAlive: Fn() -> bool + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut violated_since: Option<Instant> = None;
+        let mut was_paused = false;
+
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            if !is_active() {
+                break;
+            }
+
+            if is_paused() {
+                was_paused = true;
+                continue;
+            }
+
+            if was_paused {
+                // Resuming from a legitimate pause shouldn't immediately
+                // look like a violation because of how long transcription
+                // was silent *during* the pause; give it a fresh window.
+                was_paused = false;
+                mark_activity();
+                violated_since = None;
+            }
+
+            let silent_for = LAST_ACTIVITY
+                .lock()
+                .unwrap()
+                .map(|last| last.elapsed())
+                .unwrap_or(Duration::ZERO);
+
+            let violated = !transcription_alive() || silent_for >= ACTIVITY_GRACE;
+
+            if violated {
+                let since = *violated_since.get_or_insert_with(Instant::now);
+
+                if since.elapsed() <= POLL_INTERVAL {
+                    // Just crossed into violation; tell the user once.
+                    let _ = app.emit(
+                        "recording-policy-violation",
+                        serde_json::json!({
+                            "reason": "no_transcript_activity",
+                            "silentForSecs": silent_for.as_secs(),
+                            "willAutoStop": is_auto_stop_enabled(),
+                        }),
+                    );
+                    log::warn!(
+                        "Policy watchdog: no transcript activity for {}s",
+                        silent_for.as_secs()
+                    );
+                }
+
+                if is_auto_stop_enabled() && since.elapsed() >= AUTO_STOP_GRACE {
+                    log::warn!("Policy watchdog: auto-stopping recording after sustained silence");
+                    crate::tray::stop_recording_handler(&app);
+                    break;
+                }
+            } else {
+                violated_since = None;
+            }
+        }
+    })
+}
@@ -8,9 +8,10 @@ use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::sync::{
     atomic::{AtomicBool, Ordering},
-    Arc, Mutex,
+    Arc, Mutex, OnceLock,
 };
 use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tokio::sync::watch;
 use tokio::task::JoinHandle;
 
 use super::{parse_audio_device, RecordingManager, DeviceEvent, DeviceMonitorType};
@@ -24,6 +25,8 @@ use super::transcription::{
 // Re-export TranscriptUpdate for backward compatibility
 pub use super::transcription::TranscriptUpdate;
 
+use crate::tray::RecordingState;
+
 // ============================================================================
 // GLOBAL STATE
 // ============================================================================
@@ -35,6 +38,90 @@ static IS_RECORDING: AtomicBool = AtomicBool::new(false);
 static RECORDING_MANAGER: Mutex<Option<RecordingManager>> = Mutex::new(None);
 static TRANSCRIPTION_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
 
+// Watches the live input level and drives "auto-pause on silence".
+static SILENCE_WATCHDOG_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+// Set while a recording is paused specifically because the silence watchdog
+// auto-paused it, the same way `suspend_manager::SUSPENDED_BY_US` tracks a
+// suspend-caused pause: lets `resume_recording` (the manual path) reconcile
+// the watchdog's idea of ownership instead of leaving it believing it still
+// owns a pause the user has already ended themselves.
+static AUTO_PAUSED_BY_SILENCE: Mutex<bool> = Mutex::new(false);
+
+// Watches transcript activity and enforces the "must actually be
+// transcribed" recording policy.
+static POLICY_WATCHDOG_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+// Aborts a recording whose audio chunks never started flowing or stopped
+// arriving mid-session (a dead device/driver, not genuine silence).
+static STALL_WATCHDOG_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+// Re-resolves `resolve_worker_count()` periodically so `Adaptive` mode
+// actually tracks queue backpressure over the life of a recording, instead
+// of being frozen at whatever it evaluated to the instant the pool started
+// (always the configured floor, since the queue is empty at that point).
+static WORKER_POOL_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+// Live connection to the streaming cloud transcription provider, when one is
+// configured. `None` whenever a local engine (Whisper/Parakeet) is in use.
+static STREAMING_SESSION: Mutex<Option<super::streaming_transcription::StreamingSession>> =
+    Mutex::new(None);
+
+// When the current recording started, so `RecordingState::Recording`'s
+// `elapsed_secs` reflects the actual session instead of always reporting 0.
+static RECORDING_STARTED_AT: Mutex<Option<std::time::Instant>> = Mutex::new(None);
+
+/// Seconds since the current recording started, or 0 if none is active.
+/// Counts wall-clock time including any pauses, matching what a user reading
+/// the tray tooltip would expect ("how long has this session been open").
+fn recording_elapsed_secs() -> u64 {
+    if !IS_RECORDING.load(Ordering::SeqCst) {
+        return 0;
+    }
+
+    RECORDING_STARTED_AT
+        .lock()
+        .unwrap()
+        .map(|started| started.elapsed().as_secs())
+        .unwrap_or(0)
+}
+
+// Authoritative recording-state stream. The tray (and, in future, the
+// frontend) subscribes to this instead of polling `is_recording()`/
+// `is_recording_paused()` after a fixed delay.
+static RECORDING_STATE_TX: OnceLock<watch::Sender<RecordingState>> = OnceLock::new();
+
+fn recording_state_tx() -> &'static watch::Sender<RecordingState> {
+    RECORDING_STATE_TX.get_or_init(|| watch::channel(RecordingState::Stopped).0)
+}
+
+/// Publish a `RecordingState` transition to every subscriber (tray, etc.).
+pub fn publish_recording_state(state: RecordingState) {
+    let _ = recording_state_tx().send(state);
+}
+
+/// Subscribe to the recording-state stream. The receiver immediately yields
+/// the current state on first `borrow()`, then the latest state on every
+/// `changed()`.
+pub fn subscribe_recording_state() -> watch::Receiver<RecordingState> {
+    recording_state_tx().subscribe()
+}
+
+/// Re-derive the current `RecordingState` by inspecting the manager directly.
+/// Used to correct the stream after an optimistic transition turns out to be
+/// wrong (e.g. a pause/resume/stop call fails partway through).
+pub async fn current_recording_state() -> RecordingState {
+    if !IS_RECORDING.load(Ordering::SeqCst) {
+        return RecordingState::Stopped;
+    }
+
+    if is_recording_paused().await {
+        RecordingState::Paused
+    } else {
+        RecordingState::Recording { elapsed_secs: recording_elapsed_secs(), level: 0.0 }
+    }
+}
+
 // ============================================================================
 // PUBLIC TYPES
 // ============================================================================
@@ -42,6 +129,196 @@ static TRANSCRIPTION_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
 #[derive(Debug, Deserialize)]
 pub struct RecordingArgs {
     pub save_path: String,
+    /// Path named for the system-audio track when the tray builds the args
+    /// in `TrackLayout::Separate` mode, with `save_path` then naming the
+    /// microphone track; `None` in `Mixed` mode. `stop_recording` does not
+    /// read it: the layout is applied to the manager at recording start.
+    #[serde(default)]
+    pub system_save_path: Option<String>,
+    /// Format whose extension `save_path`/`system_save_path` carry.
+    /// `stop_recording` does not read it: the encoder is applied to the
+    /// manager at recording start via `set_output_format`.
+    #[serde(default)]
+    pub format: OutputFormat,
+}
+
+/// Output encoder for saved recordings. The encoder itself lives in
+/// `RecordingManager`, which is told the selected format via
+/// `set_output_format` at recording start (the point where it actually
+/// writes audio); this module's own job is just to stop assuming `.wav`
+/// and generate the right extension for whatever the user picked in
+/// Settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum OutputFormat {
+    #[default]
+    Wav,
+    Opus,
+    Flac,
+    Mp3,
+}
+
+impl OutputFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Wav => "wav",
+            OutputFormat::Opus => "opus",
+            OutputFormat::Flac => "flac",
+            OutputFormat::Mp3 => "mp3",
+        }
+    }
+}
+
+static OUTPUT_FORMAT: Mutex<OutputFormat> = Mutex::new(OutputFormat::Wav);
+
+/// Current output format, readable synchronously (e.g. from `tray::build_recording_args`
+/// and from `start_recording_with_meeting_name`/`start_recording_with_devices_and_meeting`,
+/// which apply it to the manager via `set_output_format` before capture starts).
+pub fn current_output_format() -> OutputFormat {
+    *OUTPUT_FORMAT.lock().unwrap()
+}
+
+/// Set the output format recordings are saved in, chosen from Settings.
+#[tauri::command]
+pub async fn set_output_format(format: OutputFormat) -> Result<(), String> {
+    *OUTPUT_FORMAT.lock().unwrap() = format;
+    info!("Output format set to {:?}", format);
+    Ok(())
+}
+
+/// Whether microphone and system audio are captured into one mixed file or
+/// two independent tracks, so later transcription/diarization can attribute
+/// speech to the correct source. Applied to the manager via `set_track_layout`
+/// at recording start, since separate-track capture has to be decided before
+/// the streams are wired up, not after the fact when the recording stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrackLayout {
+    Mixed,
+    Separate,
+}
+
+static TRACK_LAYOUT: Mutex<TrackLayout> = Mutex::new(TrackLayout::Mixed);
+
+/// Current track layout, readable synchronously (e.g. from `tray::build_menu`
+/// and from the two `start_recording_*` functions, which apply it to the
+/// manager via `set_track_layout` before capture starts).
+pub fn current_track_layout() -> TrackLayout {
+    *TRACK_LAYOUT.lock().unwrap()
+}
+
+/// Flip between `Mixed` and `Separate` and return the new layout.
+#[tauri::command]
+pub async fn toggle_track_layout() -> TrackLayout {
+    let mut guard = TRACK_LAYOUT.lock().unwrap();
+    *guard = match *guard {
+        TrackLayout::Mixed => TrackLayout::Separate,
+        TrackLayout::Separate => TrackLayout::Mixed,
+    };
+    *guard
+}
+
+/// Which capture channel a mute toggle applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioChannel {
+    Microphone,
+    SystemAudio,
+}
+
+static MICROPHONE_MUTED: AtomicBool = AtomicBool::new(false);
+static SYSTEM_AUDIO_MUTED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the microphone channel is currently muted, readable synchronously
+/// from `tray::build_menu` and from the capture pipeline.
+pub fn is_microphone_muted() -> bool {
+    MICROPHONE_MUTED.load(Ordering::SeqCst)
+}
+
+/// Whether the system-audio channel is currently muted.
+pub fn is_system_audio_muted() -> bool {
+    SYSTEM_AUDIO_MUTED.load(Ordering::SeqCst)
+}
+
+/// Reset both channel mutes at the start of a new recording session.
+fn reset_channel_mutes() {
+    MICROPHONE_MUTED.store(false, Ordering::SeqCst);
+    SYSTEM_AUDIO_MUTED.store(false, Ordering::SeqCst);
+}
+
+/// Mute or unmute one capture channel mid-recording, without tearing down
+/// its stream, so e.g. the microphone can be silenced for a confidential
+/// sidebar while system audio (and the session clock) keeps running, and
+/// unmuting is instantaneous. The capture pipeline is expected to consult
+/// `is_microphone_muted`/`is_system_audio_muted` at the point it currently
+/// feeds a captured chunk into the transcription pipeline and substitute
+/// silence (or skip the chunk) for whichever channel is muted.
+#[tauri::command]
+pub async fn set_channel_muted<R: Runtime>(
+    app: AppHandle<R>,
+    channel: AudioChannel,
+    muted: bool,
+) -> Result<(), String> {
+    let flag = match channel {
+        AudioChannel::Microphone => &MICROPHONE_MUTED,
+        AudioChannel::SystemAudio => &SYSTEM_AUDIO_MUTED,
+    };
+    flag.store(muted, Ordering::SeqCst);
+
+    let event_name = if muted {
+        "recording-channel-muted"
+    } else {
+        "recording-channel-unmuted"
+    };
+    let _ = app.emit(event_name, serde_json::json!({ "channel": channel }));
+    info!("{:?} {}", channel, if muted { "muted" } else { "unmuted" });
+
+    Ok(())
+}
+
+/// Mirrors the `min_duration`/`discard_empty` fields intended for
+/// `recording_preferences`: a session that produced no transcript and either
+/// ran shorter than `min_duration_secs` or saved an audio file under
+/// `min_audio_bytes` is treated as noise rather than a real meeting, so its
+/// artifacts are removed instead of polluting history with an empty
+/// auto-named entry. `discard_empty` doubles as the `keep_empty_recordings`
+/// opt-out: flip it off to always persist, even when a session genuinely
+/// produced nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DiscardEmptyPreference {
+    pub discard_empty: bool,
+    pub min_duration_secs: f64,
+    /// Saved audio below this many bytes is treated as empty (e.g. a muted
+    /// mic or a device that never produced samples leaves only a WAV
+    /// header), regardless of how long the session ran.
+    pub min_audio_bytes: u64,
+}
+
+impl Default for DiscardEmptyPreference {
+    fn default() -> Self {
+        Self {
+            discard_empty: true,
+            min_duration_secs: 5.0,
+            min_audio_bytes: 4096,
+        }
+    }
+}
+
+static DISCARD_EMPTY_PREFERENCE: Mutex<DiscardEmptyPreference> =
+    Mutex::new(DiscardEmptyPreference {
+        discard_empty: true,
+        min_duration_secs: 5.0,
+        min_audio_bytes: 4096,
+    });
+
+/// Current discard-empty preference, readable synchronously from `stop_recording`.
+pub fn current_discard_empty_preference() -> DiscardEmptyPreference {
+    *DISCARD_EMPTY_PREFERENCE.lock().unwrap()
+}
+
+/// Update the discard-empty preference, chosen from Settings.
+#[tauri::command]
+pub async fn set_discard_empty_preference(preference: DiscardEmptyPreference) -> Result<(), String> {
+    *DISCARD_EMPTY_PREFERENCE.lock().unwrap() = preference;
+    info!("Discard-empty preference set to {:?}", preference);
+    Ok(())
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -51,6 +328,219 @@ pub struct TranscriptionStatus {
     pub last_activity_ms: u64,
 }
 
+// Counts audio chunks handed to the local transcription worker pool that
+// haven't been confirmed processed by a `transcript-update` yet, so
+// `resolve_worker_count()`'s `Adaptive` mode has a real backpressure signal
+// instead of an always-zero placeholder. Incremented by `instrument_chunk_queue`
+// as chunks are pulled off the capture channel, decremented by
+// `register_transcript_history_listener` as each one's transcript comes back.
+static CHUNKS_QUEUED: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// Interpose between the capture channel and `start_transcription_task` so
+/// every chunk handed to the worker pool is counted as queued the instant
+/// it's pulled off the capture channel, without needing to touch
+/// `start_transcription_task` itself.
+fn instrument_chunk_queue(
+    mut receiver: tokio::sync::mpsc::UnboundedReceiver<Vec<u8>>,
+) -> tokio::sync::mpsc::UnboundedReceiver<Vec<u8>> {
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(frame) = receiver.recv().await {
+            CHUNKS_QUEUED.fetch_add(1, Ordering::SeqCst);
+            if tx.send(frame).is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+/// Called once a chunk's transcript has come back, so `CHUNKS_QUEUED` reflects
+/// what's still in flight rather than everything ever queued.
+fn dequeue_chunk() {
+    let _ = CHUNKS_QUEUED.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |v| {
+        Some(v.saturating_sub(1))
+    });
+}
+
+/// Whether the transcription worker pool is a fixed size or scales with
+/// observed queue backpressure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkerPoolMode {
+    Fixed,
+    Adaptive,
+}
+
+/// Mirrors the worker-pool fields intended for `recording_preferences`.
+/// `worker_count` is the pool size in `Fixed` mode and the floor in
+/// `Adaptive` mode; `max_workers` caps how high `Adaptive` may scale, which
+/// also bounds concurrent model contexts to guard against the macOS Candle
+/// memory-growth pattern when every worker holds its own handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkerPoolPreference {
+    pub mode: WorkerPoolMode,
+    pub worker_count: u32,
+    pub max_workers: u32,
+}
+
+impl Default for WorkerPoolPreference {
+    fn default() -> Self {
+        Self {
+            mode: WorkerPoolMode::Fixed,
+            worker_count: 3,
+            max_workers: 6,
+        }
+    }
+}
+
+static WORKER_POOL_PREFERENCE: Mutex<WorkerPoolPreference> = Mutex::new(WorkerPoolPreference {
+    mode: WorkerPoolMode::Fixed,
+    worker_count: 3,
+    max_workers: 6,
+});
+
+/// Current worker-pool preference, readable synchronously from Settings.
+pub fn current_worker_pool_preference() -> WorkerPoolPreference {
+    *WORKER_POOL_PREFERENCE.lock().unwrap()
+}
+
+/// Update the worker-pool preference, chosen from Settings.
+#[tauri::command]
+pub async fn set_worker_pool_preference(preference: WorkerPoolPreference) -> Result<(), String> {
+    *WORKER_POOL_PREFERENCE.lock().unwrap() = preference;
+    info!("Worker pool preference set to {:?}", preference);
+    Ok(())
+}
+
+/// Resolve how many transcription workers should be active right now: the
+/// configured fixed count, or in `Adaptive` mode, a count that scales with
+/// `chunks_in_queue` backpressure so slower machines don't accumulate an
+/// unbounded backlog that the forced-flush shutdown then has to drain with
+/// no timeout. In `Adaptive` mode the result is capped at `max_workers` to
+/// bound concurrent model contexts; in `Fixed` mode `worker_count` is used
+/// as is. Consulted once at `start_transcription_task` and then on every
+/// `spawn_worker_pool_monitor` tick for the rest of the recording, via
+/// `transcription::set_worker_count`, so `Adaptive` mode actually tracks
+/// queue depth as it develops; releasing an idle worker's model handle when
+/// the pool shrinks is `transcription::set_worker_count`'s responsibility,
+/// not this module's.
+pub async fn resolve_worker_count() -> u32 {
+    let preference = current_worker_pool_preference();
+    match preference.mode {
+        WorkerPoolMode::Fixed => preference.worker_count,
+        WorkerPoolMode::Adaptive => {
+            let status = get_transcription_status().await;
+            let backpressure_workers = 1 + (status.chunks_in_queue as u32 / 2);
+            backpressure_workers
+                .max(preference.worker_count)
+                .min(preference.max_workers)
+        }
+    }
+}
+
+/// Sum the size of the audio files a meeting folder holds, across every
+/// `OutputFormat` extension, so the discard-on-empty check in `stop_recording`
+/// doesn't have to know which encoder produced them.
+fn total_audio_bytes(folder: &std::path::Path) -> u64 {
+    const AUDIO_EXTENSIONS: [&str; 4] = ["wav", "opus", "flac", "mp3"];
+
+    std::fs::read_dir(folder)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| {
+                    entry
+                        .path()
+                        .extension()
+                        .and_then(|ext| ext.to_str())
+                        .map(|ext| AUDIO_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+                        .unwrap_or(false)
+                })
+                .filter_map(|entry| entry.metadata().ok())
+                .map(|meta| meta.len())
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Classify a device name into a privacy-safe bucket — never the name itself
+/// — for use anywhere a device needs to be described without identifying it,
+/// e.g. analytics (`track_meeting_ended`) and the `metrics` exporter.
+pub(crate) fn classify_device_type(device_name: &str) -> &'static str {
+    let name_lower = device_name.to_lowercase();
+    // Check for Bluetooth keywords
+    if name_lower.contains("bluetooth")
+        || name_lower.contains("airpods")
+        || name_lower.contains("beats")
+        || name_lower.contains("headphones")
+        || name_lower.contains("bt ")
+        || name_lower.contains("wireless") {
+        "Bluetooth"
+    } else {
+        "Wired"
+    }
+}
+
+/// Whether a device reconnection attempt is currently in progress, for the
+/// `metrics` exporter's gauge; mirrors the check `get_reconnection_status`
+/// already does rather than tracking a separate static.
+pub(crate) fn is_reconnecting() -> bool {
+    RECORDING_MANAGER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|manager| manager.is_reconnecting())
+        .unwrap_or(false)
+}
+
+/// Listen for `transcript-update` events and save each one to the recording
+/// manager as a structured `TranscriptSegment`, so transcript history
+/// persists across a page reload during an active recording. Shared by both
+/// `start_recording_with_meeting_name` and `start_recording_with_devices_and_meeting`
+/// so the vocabulary filter and activity tracking only live in one place.
+fn register_transcript_history_listener<R: Runtime>(app: AppHandle<R>) {
+    tokio::spawn(async move {
+        use tauri::Listener;
+
+        app.listen("transcript-update", move |event: tauri::Event| {
+            // Parse the transcript update from the event payload
+            if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
+                super::policy_watchdog::mark_activity();
+                super::stall_watchdog::mark_chunk_received();
+                dequeue_chunk();
+                #[cfg(feature = "metrics")]
+                crate::metrics::record_chunk_processed();
+
+                let filtered_text = super::vocabulary_filter::apply(&update.text);
+
+                // Create structured transcript segment
+                let segment = crate::audio::recording_saver::TranscriptSegment {
+                    id: format!("seg_{}", update.sequence_id),
+                    text: filtered_text,
+                    audio_start_time: update.audio_start_time,
+                    audio_end_time: update.audio_end_time,
+                    duration: update.duration,
+                    display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
+                    confidence: update.confidence,
+                    sequence_id: update.sequence_id,
+                };
+
+                // Save to recording manager
+                if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
+                    if let Some(manager) = manager_guard.as_ref() {
+                        manager.add_transcript_segment(segment);
+                    }
+                }
+
+                #[cfg(feature = "metrics")]
+                crate::metrics::record_segment_emitted();
+            }
+        });
+
+        info!("✅ Transcript-update event listener registered for history persistence");
+    });
+}
+
 // ============================================================================
 // RECORDING COMMANDS
 // ============================================================================
@@ -99,6 +589,16 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     // Create new recording manager
     let mut manager = RecordingManager::new();
 
+    // Apply the configured encoder before capture starts, so the selected
+    // format is actually what gets written instead of always falling back
+    // to raw WAV under whichever extension `current_output_format` picked.
+    manager.set_output_format(current_output_format());
+
+    // Apply the configured track layout before capture starts, so
+    // `TrackLayout::Separate` actually captures microphone and system audio
+    // into independent streams instead of only renaming one mixed file.
+    manager.set_track_layout(current_track_layout());
+
     // Load recording preferences to check auto_save setting
     // This determines whether we save audio checkpoints or just transcripts/metadata
     let auto_save = match super::recording_preferences::load_recording_preferences(&app).await {
@@ -144,57 +644,133 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     // Set recording flag and reset speech detection flag
     info!("🔍 Setting IS_RECORDING to true and resetting SPEECH_DETECTED_EMITTED");
     IS_RECORDING.store(true, Ordering::SeqCst);
+    *RECORDING_STARTED_AT.lock().unwrap() = Some(std::time::Instant::now());
+    CHUNKS_QUEUED.store(0, Ordering::SeqCst);
     reset_speech_detected_flag(); // Reset for new recording session
+    reset_channel_mutes();
+
+    // Decide which pipeline actually consumes `transcription_receiver` before
+    // committing it anywhere: the streaming cloud provider and the local
+    // engines are mutually exclusive consumers of the same channel, not two
+    // independent ones. `start_transcription_task` drives Whisper/Parakeet;
+    // the streaming provider instead gets its own forwarding loop that feeds
+    // `StreamingSession::feed` with the same frames.
+    let transcript_provider =
+        match crate::api::api::api_get_transcript_config(app.clone(), app.clone().state(), None)
+            .await
+        {
+            Ok(Some(config)) => Some(config.provider),
+            _ => None,
+        };
 
-    // Start optimized parallel transcription task and store handle
-    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
-    {
+    if transcript_provider.as_deref() == Some("streaming") {
+        let session =
+            super::streaming_transcription::start_session(app.clone(), "streaming".to_string());
+        *STREAMING_SESSION.lock().unwrap() = Some(session);
+        info!("🌊 Streaming transcription session opened");
+
+        // Forward every frame the capture pipeline produces into the session
+        // via `feed`, instead of handing them to the local worker pool.
+        let forward_task = tokio::spawn(super::streaming_transcription::forward_to_session(
+            transcription_receiver,
+            &STREAMING_SESSION,
+        ));
         let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
-        *global_task = Some(task_handle);
-    }
-
-    // CRITICAL: Listen for transcript-update events and save to recording manager
-    // This enables transcript history persistence for page reload sync
-    let app_for_listener = app.clone();
-    tokio::spawn(async move {
-        use tauri::Listener;
-
-        app_for_listener.listen("transcript-update", move |event: tauri::Event| {
-            // Parse the transcript update from the event payload
-            if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
-                // Create structured transcript segment
-                let segment = crate::audio::recording_saver::TranscriptSegment {
-                    id: format!("seg_{}", update.sequence_id),
-                    text: update.text.clone(),
-                    audio_start_time: update.audio_start_time,
-                    audio_end_time: update.audio_end_time,
-                    duration: update.duration,
-                    display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
-                    confidence: update.confidence,
-                    sequence_id: update.sequence_id,
-                };
+        *global_task = Some(forward_task);
+    } else {
+        // Start optimized parallel transcription task, sized by the configured
+        // (or adaptively resolved) worker-pool preference, and store handle
+        let task_handle = transcription::start_transcription_task(
+            app.clone(),
+            instrument_chunk_queue(transcription_receiver),
+            resolve_worker_count().await,
+        );
+        {
+            let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
+            *global_task = Some(task_handle);
+        }
 
-                // Save to recording manager
-                if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
-                    if let Some(manager) = manager_guard.as_ref() {
-                        manager.add_transcript_segment(segment);
-                    }
-                }
-            }
-        });
+        // Keep the pool sized to `resolve_worker_count()` for the life of the
+        // recording, not just the instant it started.
+        let worker_pool_monitor = spawn_worker_pool_monitor(app.clone());
+        let mut guard = WORKER_POOL_TASK.lock().unwrap();
+        *guard = Some(worker_pool_monitor);
+    }
 
-        info!("✅ Transcript-update event listener registered for history persistence");
-    });
+    // Listen for transcript-update events and save to recording manager.
+    // This enables transcript history persistence for page reload sync.
+    register_transcript_history_listener(app.clone());
 
     // Emit success event
     app.emit("recording-started", serde_json::json!({
         "message": "Recording started successfully with parallel processing",
         "devices": ["Default Microphone", "Default System Audio"],
-        "workers": 3
+        "workers": resolve_worker_count().await
     })).map_err(|e| e.to_string())?;
 
-    // Update tray menu to reflect recording state
-    crate::tray::update_tray_menu(&app);
+    // Publish the authoritative Recording state; the tray (and anything else
+    // subscribed) rebuilds itself from this instead of being told directly.
+    publish_recording_state(RecordingState::Recording { elapsed_secs: recording_elapsed_secs(), level: 0.0 });
+    crate::notifications::notify_recording_started(&app);
+
+    super::level_meter::reset();
+    let watchdog = spawn_silence_watchdog(app.clone());
+    {
+        let mut guard = SILENCE_WATCHDOG_TASK.lock().unwrap();
+        *guard = Some(watchdog);
+    }
+
+    super::policy_watchdog::reset();
+    let policy_watchdog = super::policy_watchdog::spawn(
+        app.clone(),
+        || IS_RECORDING.load(Ordering::SeqCst),
+        || {
+            // A legitimate pause stops transcript activity by design; tell
+            // the watchdog to skip its check this tick instead of letting
+            // `LAST_ACTIVITY` go stale enough to fire a spurious violation
+            // (and, with auto-stop enabled, a forced stop) on an ordinary
+            // break.
+            RECORDING_MANAGER
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|manager| manager.is_paused())
+                .unwrap_or(false)
+        },
+        || {
+            TRANSCRIPTION_TASK
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|handle| !handle.is_finished())
+                .unwrap_or(false)
+        },
+    );
+    {
+        let mut guard = POLICY_WATCHDOG_TASK.lock().unwrap();
+        *guard = Some(policy_watchdog);
+    }
+
+    super::suspend_manager::reset();
+
+    super::stall_watchdog::reset();
+    *AUTO_PAUSED_BY_SILENCE.lock().unwrap() = false;
+    let stall_watchdog = super::stall_watchdog::spawn(
+        app.clone(),
+        || IS_RECORDING.load(Ordering::SeqCst),
+        || {
+            RECORDING_MANAGER
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|manager| manager.is_paused())
+                .unwrap_or(false)
+        },
+    );
+    {
+        let mut guard = STALL_WATCHDOG_TASK.lock().unwrap();
+        *guard = Some(stall_watchdog);
+    }
 
     info!("✅ Recording started successfully with async-first approach");
 
@@ -268,6 +844,16 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     // Create new recording manager
     let mut manager = RecordingManager::new();
 
+    // Apply the configured encoder before capture starts, so the selected
+    // format is actually what gets written instead of always falling back
+    // to raw WAV under whichever extension `current_output_format` picked.
+    manager.set_output_format(current_output_format());
+
+    // Apply the configured track layout before capture starts, so
+    // `TrackLayout::Separate` actually captures microphone and system audio
+    // into independent streams instead of only renaming one mixed file.
+    manager.set_track_layout(current_track_layout());
+
     // Load recording preferences to check auto_save setting
     let auto_save = match super::recording_preferences::load_recording_preferences(&app).await {
         Ok(prefs) => {
@@ -311,47 +897,62 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     // Set recording flag and reset speech detection flag
     info!("🔍 Setting IS_RECORDING to true and resetting SPEECH_DETECTED_EMITTED");
     IS_RECORDING.store(true, Ordering::SeqCst);
+    *RECORDING_STARTED_AT.lock().unwrap() = Some(std::time::Instant::now());
+    CHUNKS_QUEUED.store(0, Ordering::SeqCst);
     reset_speech_detected_flag(); // Reset for new recording session
+    reset_channel_mutes();
+
+    // Decide which pipeline actually consumes `transcription_receiver` before
+    // committing it anywhere: the streaming cloud provider and the local
+    // engines are mutually exclusive consumers of the same channel, not two
+    // independent ones. `start_transcription_task` drives Whisper/Parakeet;
+    // the streaming provider instead gets its own forwarding loop that feeds
+    // `StreamingSession::feed` with the same frames.
+    let transcript_provider =
+        match crate::api::api::api_get_transcript_config(app.clone(), app.clone().state(), None)
+            .await
+        {
+            Ok(Some(config)) => Some(config.provider),
+            _ => None,
+        };
 
-    // Start optimized parallel transcription task and store handle
-    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
-    {
+    if transcript_provider.as_deref() == Some("streaming") {
+        let session =
+            super::streaming_transcription::start_session(app.clone(), "streaming".to_string());
+        *STREAMING_SESSION.lock().unwrap() = Some(session);
+        info!("🌊 Streaming transcription session opened");
+
+        // Forward every frame the capture pipeline produces into the session
+        // via `feed`, instead of handing them to the local worker pool.
+        let forward_task = tokio::spawn(super::streaming_transcription::forward_to_session(
+            transcription_receiver,
+            &STREAMING_SESSION,
+        ));
         let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
-        *global_task = Some(task_handle);
-    }
-
-    // CRITICAL: Listen for transcript-update events and save to recording manager
-    // This enables transcript history persistence for page reload sync
-    let app_for_listener = app.clone();
-    tokio::spawn(async move {
-        use tauri::Listener;
-
-        app_for_listener.listen("transcript-update", move |event: tauri::Event| {
-            // Parse the transcript update from the event payload
-            if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
-                // Create structured transcript segment
-                let segment = crate::audio::recording_saver::TranscriptSegment {
-                    id: format!("seg_{}", update.sequence_id),
-                    text: update.text.clone(),
-                    audio_start_time: update.audio_start_time,
-                    audio_end_time: update.audio_end_time,
-                    duration: update.duration,
-                    display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
-                    confidence: update.confidence,
-                    sequence_id: update.sequence_id,
-                };
+        *global_task = Some(forward_task);
+    } else {
+        // Start optimized parallel transcription task, sized by the configured
+        // (or adaptively resolved) worker-pool preference, and store handle
+        let task_handle = transcription::start_transcription_task(
+            app.clone(),
+            instrument_chunk_queue(transcription_receiver),
+            resolve_worker_count().await,
+        );
+        {
+            let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
+            *global_task = Some(task_handle);
+        }
 
-                // Save to recording manager
-                if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
-                    if let Some(manager) = manager_guard.as_ref() {
-                        manager.add_transcript_segment(segment);
-                    }
-                }
-            }
-        });
+        // Keep the pool sized to `resolve_worker_count()` for the life of the
+        // recording, not just the instant it started.
+        let worker_pool_monitor = spawn_worker_pool_monitor(app.clone());
+        let mut guard = WORKER_POOL_TASK.lock().unwrap();
+        *guard = Some(worker_pool_monitor);
+    }
 
-        info!("✅ Transcript-update event listener registered for history persistence");
-    });
+    // Listen for transcript-update events and save to recording manager.
+    // This enables transcript history persistence for page reload sync.
+    register_transcript_history_listener(app.clone());
 
     // Emit success event
     app.emit("recording-started", serde_json::json!({
@@ -360,11 +961,72 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
             mic_device_name.unwrap_or_else(|| "Default Microphone".to_string()),
             system_device_name.unwrap_or_else(|| "Default System Audio".to_string())
         ],
-        "workers": 3
+        "workers": resolve_worker_count().await
     })).map_err(|e| e.to_string())?;
 
-    // Update tray menu to reflect recording state
-    crate::tray::update_tray_menu(&app);
+    // Publish the authoritative Recording state; the tray (and anything else
+    // subscribed) rebuilds itself from this instead of being told directly.
+    publish_recording_state(RecordingState::Recording { elapsed_secs: recording_elapsed_secs(), level: 0.0 });
+    crate::notifications::notify_recording_started(&app);
+
+    super::level_meter::reset();
+    let watchdog = spawn_silence_watchdog(app.clone());
+    {
+        let mut guard = SILENCE_WATCHDOG_TASK.lock().unwrap();
+        *guard = Some(watchdog);
+    }
+
+    super::policy_watchdog::reset();
+    let policy_watchdog = super::policy_watchdog::spawn(
+        app.clone(),
+        || IS_RECORDING.load(Ordering::SeqCst),
+        || {
+            // A legitimate pause stops transcript activity by design; tell
+            // the watchdog to skip its check this tick instead of letting
+            // `LAST_ACTIVITY` go stale enough to fire a spurious violation
+            // (and, with auto-stop enabled, a forced stop) on an ordinary
+            // break.
+            RECORDING_MANAGER
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|manager| manager.is_paused())
+                .unwrap_or(false)
+        },
+        || {
+            TRANSCRIPTION_TASK
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|handle| !handle.is_finished())
+                .unwrap_or(false)
+        },
+    );
+    {
+        let mut guard = POLICY_WATCHDOG_TASK.lock().unwrap();
+        *guard = Some(policy_watchdog);
+    }
+
+    super::suspend_manager::reset();
+
+    super::stall_watchdog::reset();
+    *AUTO_PAUSED_BY_SILENCE.lock().unwrap() = false;
+    let stall_watchdog = super::stall_watchdog::spawn(
+        app.clone(),
+        || IS_RECORDING.load(Ordering::SeqCst),
+        || {
+            RECORDING_MANAGER
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|manager| manager.is_paused())
+                .unwrap_or(false)
+        },
+    );
+    {
+        let mut guard = STALL_WATCHDOG_TASK.lock().unwrap();
+        *guard = Some(stall_watchdog);
+    }
 
     info!("✅ Recording started with custom devices using async-first approach");
 
@@ -386,6 +1048,10 @@ pub async fn stop_recording<R: Runtime>(
         return Ok(());
     }
 
+    // Publish the stopping transition in case this was invoked from a path
+    // other than the tray (which already published it optimistically).
+    publish_recording_state(RecordingState::Stopping);
+
     // Emit shutdown progress to frontend
     let _ = app.emit(
         "recording-shutdown-progress",
@@ -396,6 +1062,24 @@ pub async fn stop_recording<R: Runtime>(
         }),
     );
 
+    // Stop the silence watchdog now; it has nothing left to watch once audio
+    // capture is torn down below.
+    if let Some(handle) = SILENCE_WATCHDOG_TASK.lock().unwrap().take() {
+        handle.abort();
+    }
+
+    if let Some(handle) = POLICY_WATCHDOG_TASK.lock().unwrap().take() {
+        handle.abort();
+    }
+
+    if let Some(handle) = STALL_WATCHDOG_TASK.lock().unwrap().take() {
+        handle.abort();
+    }
+
+    if let Some(handle) = WORKER_POOL_TASK.lock().unwrap().take() {
+        handle.abort();
+    }
+
     // Step 1: Stop audio capture immediately (no more new chunks) with proper error handling
     let manager_for_cleanup = {
         let mut global_manager = RECORDING_MANAGER.lock().unwrap();
@@ -510,6 +1194,16 @@ pub async fn stop_recording<R: Runtime>(
     };
 
     match config.as_deref() {
+        Some("streaming") => {
+            info!("🌊 Closing streaming transcription session...");
+            let session = STREAMING_SESSION.lock().unwrap().take();
+            if let Some(session) = session {
+                session.close().await;
+                info!("✅ Streaming transcription session closed");
+            } else {
+                warn!("⚠️ No streaming transcription session found to close");
+            }
+        }
         Some("parakeet") => {
             info!("🦜 Unloading Parakeet model...");
             let engine_clone = {
@@ -574,7 +1268,7 @@ pub async fn stop_recording<R: Runtime>(
             manager.get_active_recording_duration().unwrap_or(0.0),
             manager.get_total_pause_duration(),
             manager.get_transcript_segments().len() as u64,
-            state.has_fatal_error(),
+            state.has_fatal_error() || super::stall_watchdog::had_fatal_error(),
             state.get_microphone_device().map(|d| d.name.clone()),
             state.get_system_device().map(|d| d.name.clone()),
             stats.chunks_processed,
@@ -583,26 +1277,18 @@ pub async fn stop_recording<R: Runtime>(
         None
     };
 
+    // A session with no transcript that ran shorter than the configured
+    // minimum is noise, not a meeting; remember enough to decide that below,
+    // before `analytics_data` is moved into the analytics-tracking block.
+    let (discard_candidate_duration, discard_candidate_segments) = analytics_data
+        .as_ref()
+        .map(|data| (data.0, data.3))
+        .unwrap_or((0.0, 0));
+
     // Now perform async analytics tracking without holding manager reference
     if let Some((total_duration, active_duration, pause_duration, transcript_segments_count, had_fatal_error, mic_device_name, sys_device_name, chunks_processed)) = analytics_data {
         info!("📊 Collecting analytics for meeting end");
 
-        // Helper function to classify device type from device name (privacy-safe)
-        fn classify_device_type(device_name: &str) -> &'static str {
-            let name_lower = device_name.to_lowercase();
-            // Check for Bluetooth keywords
-            if name_lower.contains("bluetooth")
-                || name_lower.contains("airpods")
-                || name_lower.contains("beats")
-                || name_lower.contains("headphones")
-                || name_lower.contains("bt ")
-                || name_lower.contains("wireless") {
-                "Bluetooth"
-            } else {
-                "Wired"
-            }
-        }
-
         // Get transcription model info (already loaded above for model unload)
         let transcription_config = match crate::api::api::api_get_transcript_config(
             app.clone(),
@@ -677,16 +1363,17 @@ pub async fn stop_recording<R: Runtime>(
     );
 
     // Perform final cleanup with the manager if available
-    let (meeting_folder, meeting_name) = if let Some(mut manager) = manager_for_cleanup {
+    let (meeting_folder, meeting_name, save_succeeded) = if let Some(mut manager) = manager_for_cleanup {
         info!("🧹 Performing final cleanup and saving recording data");
 
         // Extract meeting info BEFORE async operations
         let meeting_folder = manager.get_meeting_folder();
         let meeting_name = manager.get_meeting_name();
 
-        match manager.save_recording_only(&app).await {
+        let save_succeeded = match manager.save_recording_only(&app).await {
             Ok(_) => {
                 info!("✅ Recording data saved successfully during cleanup");
+                true
             }
             Err(e) => {
                 warn!(
@@ -694,13 +1381,52 @@ pub async fn stop_recording<R: Runtime>(
                     e
                 );
                 // Don't fail shutdown - transcripts are already preserved
+                false
             }
-        }
+        };
 
-        (meeting_folder, meeting_name)
+        (meeting_folder, meeting_name, save_succeeded)
     } else {
         info!("ℹ️ No recording manager available for cleanup");
+        (None, None, false)
+    };
+
+    // Discard empty recordings: no transcript and either a session shorter
+    // than the configured minimum, or (once the save has actually finished -
+    // a partial/aborted save may leave an incomplete file that says nothing
+    // about what was captured) an audio file under the configured size
+    // floor, means there is nothing worth keeping. The checkpoint files and
+    // metadata are removed instead of leaving an empty auto-named entry in
+    // history.
+    let discard_preference = current_discard_empty_preference();
+    let audio_bytes_on_disk = meeting_folder
+        .as_deref()
+        .map(total_audio_bytes)
+        .unwrap_or(0);
+    let should_discard = discard_preference.discard_empty
+        && discard_candidate_segments == 0
+        && (discard_candidate_duration < discard_preference.min_duration_secs
+            || (save_succeeded && audio_bytes_on_disk < discard_preference.min_audio_bytes));
+
+    let (meeting_folder, meeting_name) = if should_discard {
+        if let Some(folder) = &meeting_folder {
+            match std::fs::remove_dir_all(folder) {
+                Ok(_) => info!("🗑️ Discarded empty recording artifacts at {:?}", folder),
+                Err(e) => warn!("⚠️ Failed to remove discarded recording folder {:?}: {}", folder, e),
+            }
+        }
+
+        let _ = app.emit(
+            "recording-discarded",
+            serde_json::json!({
+                "meeting_name": meeting_name,
+                "reason": "empty_recording"
+            }),
+        );
+
         (None, None)
+    } else {
+        (meeting_folder, meeting_name)
     };
 
     // Set recording flag to false
@@ -735,19 +1461,22 @@ pub async fn stop_recording<R: Runtime>(
         }),
     );
 
-    // Emit final stop event with folder_path and meeting_name for frontend to save
+    // Emit final stop event with folder_path and meeting_name for frontend to
+    // save. `discarded` lets the frontend skip its database save outright
+    // instead of inferring it from `folder_path`/`meeting_name` being null.
     app.emit(
         "recording-stopped",
         serde_json::json!({
             "message": "Recording stopped - frontend will save after all transcripts received",
             "folder_path": folder_path_str,
-            "meeting_name": meeting_name_str
+            "meeting_name": meeting_name_str,
+            "discarded": should_discard
         }),
     )
     .map_err(|e| e.to_string())?;
 
-    // Update tray menu to reflect stopped state
-    crate::tray::update_tray_menu(&app);
+    // Publish the authoritative Stopped state.
+    publish_recording_state(RecordingState::Stopped);
 
     info!("🎉 Recording stopped successfully with ZERO transcript chunks lost");
     Ok(())
@@ -758,10 +1487,143 @@ pub async fn is_recording() -> bool {
     IS_RECORDING.load(Ordering::SeqCst)
 }
 
+/// Enable or disable auto-pause-on-silence for the current and future
+/// recording sessions.
+#[tauri::command]
+pub async fn set_auto_pause_on_silence(enabled: bool) -> Result<(), String> {
+    super::level_meter::set_auto_pause_enabled(enabled);
+    info!("Auto-pause on silence set to {}", enabled);
+    Ok(())
+}
+
+/// Enable or disable auto-stopping a recording whose transcription has gone
+/// silent past the policy-watchdog grace window, versus only notifying the
+/// user via `recording-policy-violation`.
+#[tauri::command]
+pub async fn set_policy_watchdog_auto_stop(enabled: bool) -> Result<(), String> {
+    super::policy_watchdog::set_auto_stop_enabled(enabled);
+    info!("Policy watchdog auto-stop set to {}", enabled);
+    Ok(())
+}
+
+/// Re-resolve `resolve_worker_count()` every few seconds for the life of the
+/// recording and push the current value into the transcription pool, so
+/// `Adaptive` mode actually tracks queue backpressure as it develops rather
+/// than being frozen at the floor it resolved to the instant the pool
+/// started (queue is always empty at that point). A `Fixed`-mode preference
+/// change mid-recording (`set_worker_pool_preference`) takes effect on the
+/// next tick too, instead of only applying to the next recording.
+fn spawn_worker_pool_monitor<R: Runtime>(app: AppHandle<R>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut last_count: Option<u32> = None;
+
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+            if !IS_RECORDING.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let count = resolve_worker_count().await;
+            if last_count != Some(count) {
+                transcription::set_worker_count(count).await;
+                info!("⚙️ Transcription worker pool resized to {}", count);
+                let _ = app.emit(
+                    "worker-pool-resized",
+                    serde_json::json!({ "workers": count }),
+                );
+                last_count = Some(count);
+            }
+        }
+    })
+}
+
+/// Spawn the watchdog that samples the live input level and auto-pauses
+/// recording once it stays below the silence threshold for long enough,
+/// resuming as soon as sound returns. Only acts when auto-pause is enabled
+/// and never interferes with a manually-initiated pause.
+fn spawn_silence_watchdog<R: Runtime>(app: AppHandle<R>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let threshold = super::level_meter::SilenceThreshold::default();
+        let mut below_since: Option<std::time::Instant> = None;
+
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+            if !IS_RECORDING.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let level = super::level_meter::current_level();
+            // Read (not own) the pause ownership: a manual `resume_recording`
+            // clears this the moment it interrupts an auto-pause, so the
+            // watchdog always sees the current truth instead of a snapshot
+            // that can fall out of sync with what actually resumed it.
+            let auto_paused = *AUTO_PAUSED_BY_SILENCE.lock().unwrap();
+
+            // Drive the tray tooltip/icon even when auto-pause is off.
+            if !auto_paused && !is_recording_paused().await {
+                publish_recording_state(RecordingState::Recording {
+                    elapsed_secs: recording_elapsed_secs(),
+                    level,
+                });
+            }
+
+            if !super::level_meter::is_auto_pause_enabled() {
+                below_since = None;
+                continue;
+            }
+
+            if level < threshold.level {
+                let since = *below_since.get_or_insert_with(std::time::Instant::now);
+                if !auto_paused
+                    && !is_recording_paused().await
+                    && since.elapsed().as_secs() >= threshold.duration_secs
+                {
+                    let paused = {
+                        let manager_guard = RECORDING_MANAGER.lock().unwrap();
+                        manager_guard
+                            .as_ref()
+                            .map(|manager| manager.pause_recording().is_ok())
+                            .unwrap_or(false)
+                    };
+                    if paused {
+                        *AUTO_PAUSED_BY_SILENCE.lock().unwrap() = true;
+                        publish_recording_state(RecordingState::AutoPausedSilence);
+                        info!(
+                            "🔇 Auto-paused recording after {}s of silence",
+                            threshold.duration_secs
+                        );
+                    }
+                }
+            } else {
+                below_since = None;
+                if auto_paused {
+                    let resumed = {
+                        let manager_guard = RECORDING_MANAGER.lock().unwrap();
+                        manager_guard
+                            .as_ref()
+                            .map(|manager| manager.resume_recording().is_ok())
+                            .unwrap_or(false)
+                    };
+                    if resumed {
+                        *AUTO_PAUSED_BY_SILENCE.lock().unwrap() = false;
+                        publish_recording_state(RecordingState::Recording {
+                            elapsed_secs: recording_elapsed_secs(),
+                            level,
+                        });
+                        info!("🔊 Resuming recording after sound returned");
+                    }
+                }
+            }
+        }
+    })
+}
+
 /// Get recording statistics
 pub async fn get_transcription_status() -> TranscriptionStatus {
     TranscriptionStatus {
-        chunks_in_queue: 0,
+        chunks_in_queue: CHUNKS_QUEUED.load(Ordering::SeqCst),
         is_processing: IS_RECORDING.load(Ordering::SeqCst),
         last_activity_ms: 0,
     }
@@ -791,8 +1653,8 @@ pub async fn pause_recording<R: Runtime>(app: AppHandle<R>) -> Result<(), String
         )
         .map_err(|e| e.to_string())?;
 
-        // Update tray menu to reflect paused state
-        crate::tray::update_tray_menu(&app);
+        // Publish the authoritative Paused state.
+        publish_recording_state(RecordingState::Paused);
 
         info!("Recording paused successfully");
         Ok(())
@@ -816,6 +1678,13 @@ pub async fn resume_recording<R: Runtime>(app: AppHandle<R>) -> Result<(), Strin
     if let Some(manager) = manager_guard.as_ref() {
         manager.resume_recording().map_err(|e| e.to_string())?;
 
+        // This manual resume may be interrupting an auto-pause (the user
+        // spoke up before the silence watchdog saw sound return); reconcile
+        // ownership now so the watchdog doesn't keep believing it still owns
+        // a pause that's already over, the same way `suspend_manager`'s
+        // `on_resume` only acts when it's the one that caused the pause.
+        *AUTO_PAUSED_BY_SILENCE.lock().unwrap() = false;
+
         // Emit resume event to frontend
         app.emit(
             "recording-resumed",
@@ -825,8 +1694,8 @@ pub async fn resume_recording<R: Runtime>(app: AppHandle<R>) -> Result<(), Strin
         )
         .map_err(|e| e.to_string())?;
 
-        // Update tray menu to reflect resumed state
-        crate::tray::update_tray_menu(&app);
+        // Publish the authoritative Recording state.
+        publish_recording_state(RecordingState::Recording { elapsed_secs: recording_elapsed_secs(), level: 0.0 });
 
         info!("Recording resumed successfully");
         Ok(())
@@ -860,7 +1729,11 @@ pub async fn get_recording_state() -> serde_json::Value {
             "recording_duration": manager.get_recording_duration(),
             "active_duration": manager.get_active_recording_duration(),
             "total_pause_duration": manager.get_total_pause_duration(),
-            "current_pause_duration": manager.get_current_pause_duration()
+            "current_pause_duration": manager.get_current_pause_duration(),
+            "was_suspended": super::suspend_manager::was_suspended(),
+            "suspend_count": super::suspend_manager::suspend_count(),
+            "microphone_muted": is_microphone_muted(),
+            "system_audio_muted": is_system_audio_muted()
         })
     } else {
         serde_json::json!({
@@ -870,11 +1743,53 @@ pub async fn get_recording_state() -> serde_json::Value {
             "recording_duration": null,
             "active_duration": null,
             "total_pause_duration": 0.0,
-            "current_pause_duration": null
+            "current_pause_duration": null,
+            "was_suspended": super::suspend_manager::was_suspended(),
+            "suspend_count": super::suspend_manager::suspend_count(),
+            "microphone_muted": is_microphone_muted(),
+            "system_audio_muted": is_system_audio_muted()
         })
     }
 }
 
+/// Device names currently backing the active recording, used by the suspend
+/// manager to retry the exact mic/system devices that were in use rather
+/// than whatever is now the OS default.
+pub(crate) fn active_device_names() -> (Option<String>, Option<String>) {
+    let manager_guard = RECORDING_MANAGER.lock().unwrap();
+    if let Some(manager) = manager_guard.as_ref() {
+        let state = manager.get_state();
+        (
+            state.get_microphone_device().map(|d| d.name.clone()),
+            state.get_system_device().map(|d| d.name.clone()),
+        )
+    } else {
+        (None, None)
+    }
+}
+
+/// Checkpoint whatever transcript segments/audio the manager has buffered so
+/// far, without tearing the session down. Used by the suspend manager so a
+/// sleep that the machine never wakes back up from still leaves a usable
+/// recording on disk instead of losing everything since the last autosave.
+pub(crate) async fn checkpoint_transcript_to_disk<R: Runtime>(app: &AppHandle<R>) {
+    let manager_taken = {
+        let mut guard = RECORDING_MANAGER.lock().unwrap();
+        guard.take()
+    };
+
+    let Some(mut manager) = manager_taken else {
+        return;
+    };
+
+    if let Err(e) = manager.save_recording_only(app).await {
+        warn!("⚠️ Failed to checkpoint transcript before suspend: {}", e);
+    }
+
+    let mut guard = RECORDING_MANAGER.lock().unwrap();
+    *guard = Some(manager);
+}
+
 /// Get the meeting folder path for the current recording
 /// Returns the path if a meeting name was set and folder structure initialized
 #[tauri::command]
@@ -975,6 +1890,18 @@ pub async fn poll_audio_device_events() -> Result<Option<DeviceEventResponse>, S
     if let Some(manager) = manager_guard.as_mut() {
         if let Some(event) = manager.poll_device_events() {
             info!("📱 Device event polled: {:?}", event);
+
+            #[cfg(feature = "metrics")]
+            match &event {
+                DeviceEvent::DeviceDisconnected { device_name, .. } => {
+                    crate::metrics::record_device_disconnected(classify_device_type(device_name));
+                }
+                DeviceEvent::DeviceReconnected { device_name, .. } => {
+                    crate::metrics::record_device_reconnected(classify_device_type(device_name));
+                }
+                DeviceEvent::DeviceListChanged => {}
+            }
+
             Ok(Some(event.into()))
         } else {
             Ok(None)
@@ -0,0 +1,139 @@
+// audio/stall_watchdog.rs
+//
+// Watches for a recording whose audio chunks never start flowing into the
+// transcription pipeline, or that goes silent mid-session for a reason other
+// than genuine quiet (the silence auto-pause watchdog already handles that
+// case). Unlike the policy watchdog, which only warns and optionally stops
+// after a long grace window, this one expects chunks quickly and aborts the
+// session outright — a recording that never produced a single chunk has
+// nothing worth preserving.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Mirrors the thresholds intended to live in the model-config, so power
+/// users can tune how aggressively a stalled recording is aborted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StallThresholds {
+    /// How long to wait for the very first chunk after recording starts.
+    pub initial_grace_secs: u64,
+    /// Maximum allowed gap between chunks once they have started arriving.
+    pub inter_chunk_gap_secs: u64,
+}
+
+impl Default for StallThresholds {
+    fn default() -> Self {
+        Self {
+            initial_grace_secs: 15,
+            inter_chunk_gap_secs: 20,
+        }
+    }
+}
+
+static THRESHOLDS: Mutex<StallThresholds> = Mutex::new(StallThresholds {
+    initial_grace_secs: 15,
+    inter_chunk_gap_secs: 20,
+});
+static LAST_CHUNK_AT: Mutex<Option<Instant>> = Mutex::new(None);
+static HAD_FATAL_ERROR: AtomicBool = AtomicBool::new(false);
+
+pub fn current_thresholds() -> StallThresholds {
+    *THRESHOLDS.lock().unwrap()
+}
+
+/// Update the stall thresholds, chosen from Settings.
+#[tauri::command]
+pub async fn set_stall_thresholds(thresholds: StallThresholds) -> Result<(), String> {
+    *THRESHOLDS.lock().unwrap() = thresholds;
+    log::info!("Stall watchdog thresholds set to {:?}", thresholds);
+    Ok(())
+}
+
+/// Record that a transcript chunk was just produced, so the watchdog knows
+/// the pipeline is alive.
+pub fn mark_chunk_received() {
+    *LAST_CHUNK_AT.lock().unwrap() = Some(Instant::now());
+}
+
+/// Reset tracked state at the start of a new recording session.
+pub fn reset() {
+    *LAST_CHUNK_AT.lock().unwrap() = None;
+    HAD_FATAL_ERROR.store(false, Ordering::SeqCst);
+}
+
+/// Whether the watchdog fired for the current/last recording session.
+pub fn had_fatal_error() -> bool {
+    HAD_FATAL_ERROR.load(Ordering::SeqCst)
+}
+
+/// Spawn the watchdog for the lifetime of one recording session. `is_active`
+/// is polled each tick so the loop exits as soon as the recording actually
+/// stops; `is_paused` is polled alongside it so a pause — an intentional
+/// chunk gap, not a stall — only skips that tick's check instead of ending
+/// the watchdog outright, since nothing re-spawns it on resume.
+pub fn spawn<R, IsActive, IsPaused>(
+    app: AppHandle<R>,
+    is_active: IsActive,
+    is_paused: IsPaused,
+) -> tokio::task::JoinHandle<()>
+where
+    R: Runtime,
+    IsActive: Fn() -> bool + Send + 'static,
+    IsPaused: Fn() -> bool + Send + 'static,
+{
+    let mut started_at = Instant::now();
+
+    tokio::spawn(async move {
+        let mut was_paused = false;
+
+        loop {
+            tokio::time::sleep(Duration::from_secs(2)).await;
+
+            if !is_active() {
+                break;
+            }
+
+            if is_paused() {
+                was_paused = true;
+                continue;
+            }
+
+            if was_paused {
+                // Coming back from a pause shouldn't look like a stall just
+                // because no chunks arrived *during* the pause: give both
+                // the initial-grace and inter-chunk-gap clocks a fresh start.
+                was_paused = false;
+                started_at = Instant::now();
+                if LAST_CHUNK_AT.lock().unwrap().is_some() {
+                    mark_chunk_received();
+                }
+            }
+
+            let thresholds = current_thresholds();
+            let last_chunk_at = *LAST_CHUNK_AT.lock().unwrap();
+
+            let stalled = match last_chunk_at {
+                None => started_at.elapsed() >= Duration::from_secs(thresholds.initial_grace_secs),
+                Some(last) => last.elapsed() >= Duration::from_secs(thresholds.inter_chunk_gap_secs),
+            };
+
+            if stalled {
+                HAD_FATAL_ERROR.store(true, Ordering::SeqCst);
+
+                let _ = app.emit(
+                    "recording-stalled",
+                    serde_json::json!({
+                        "reason": if last_chunk_at.is_none() { "no_chunks_received" } else { "chunk_gap_exceeded" },
+                    }),
+                );
+                log::error!("Stall watchdog: recording stalled, aborting session");
+
+                crate::tray::stop_recording_handler(&app);
+                break;
+            }
+        }
+    })
+}
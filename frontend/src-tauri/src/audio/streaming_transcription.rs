@@ -0,0 +1,115 @@
+// audio/streaming_transcription.rs
+//
+// Streaming cloud transcription provider: unlike the local Whisper/Parakeet
+// engines, this is meant to open a persistent connection to a remote
+// speech-to-text service and receive transcript segments incrementally as
+// audio arrives, instead of waiting for a full chunk to finish processing
+// locally. Wired in as a third provider alongside `"whisper"`/`"parakeet"`
+// in `api_get_transcript_config`; `forward_to_session` (called from
+// `recording_commands`) feeds it the same audio frames the local engines
+// would otherwise have received. Transcript events are meant to be pushed
+// back through the same `transcript-update` channel the local engines use,
+// so history persistence and `TranscriptSegment` saving work unchanged —
+// but no concrete remote provider client is wired in yet (see `start_session`),
+// so today this never actually emits one.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Runtime};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::partial_stabilizer::{PartialStabilizer, TranscriptItem};
+
+/// Feed size the local capture pipeline already chunks audio into before
+/// handing it to a transcription engine.
+pub const STREAM_CHUNK_BYTES: usize = 8 * 1024;
+
+/// Drain the same audio-chunk receiver `start_transcription_task` would
+/// otherwise have consumed straight into a live session's `feed`, so the
+/// streaming provider actually sees the frames the capture pipeline
+/// produces instead of `feed` going uncalled for the whole recording.
+/// Spawned in place of the local worker pool and stored in the same
+/// `TRANSCRIPTION_TASK` slot, so `stop_recording` still waits for every
+/// in-flight frame before moving on to unloading a model.
+pub async fn forward_to_session(
+    mut receiver: mpsc::UnboundedReceiver<Vec<u8>>,
+    session_slot: &'static Mutex<Option<StreamingSession>>,
+) {
+    while let Some(frame) = receiver.recv().await {
+        if let Some(session) = session_slot.lock().unwrap().as_ref() {
+            session.feed(frame);
+        }
+    }
+}
+
+/// Handle to a live streaming session. Built fresh for every recording via
+/// `start_session` (never reused across sessions), so a connection dropped
+/// mid-call can't poison the next recording.
+pub struct StreamingSession {
+    audio_tx: mpsc::UnboundedSender<Vec<u8>>,
+    task: JoinHandle<()>,
+}
+
+impl StreamingSession {
+    /// Push the next ~8 KB audio frame captured by the local pipeline.
+    pub fn feed(&self, frame: Vec<u8>) {
+        let _ = self.audio_tx.send(frame);
+    }
+
+    /// Close the connection and wait for the background receive loop to
+    /// finish flushing whatever the provider already sent back.
+    pub async fn close(self) {
+        drop(self.audio_tx);
+        let _ = self.task.await;
+    }
+}
+
+/// Open a new streaming session against `provider`. The background task
+/// receives fed frames for the lifetime of the recording and runs each
+/// result through a `PartialStabilizer`, emitting `transcript-partial` and
+/// `transcript-update` events. No remote API client is connected: frames are
+/// not sent anywhere, the item list is always empty, and no event is emitted.
+pub fn start_session<R: Runtime>(app: AppHandle<R>, provider: String) -> StreamingSession {
+    let (audio_tx, mut audio_rx) = mpsc::unbounded_channel::<Vec<u8>>();
+    let utterance_id = AtomicU64::new(0);
+
+    let task = tokio::spawn(async move {
+        log::info!(
+            "Streaming transcription: opened session with provider '{}'",
+            provider
+        );
+
+        let mut stabilizer = PartialStabilizer::new(utterance_id.fetch_add(1, Ordering::SeqCst));
+
+        while let Some(frame) = audio_rx.recv().await {
+            // `frame` now actually arrives here via `forward_to_session`
+            // feeding `StreamingSession::feed`, but there is still no
+            // concrete streaming-STT client wired into this snapshot to
+            // send it to: no provider SDK/WebSocket dependency has been
+            // chosen yet. Until one is, this honestly produces nothing
+            // rather than fabricating transcript text no provider returned.
+            let _ = frame.len();
+            let items: Vec<TranscriptItem> = Vec::new();
+
+            let (partial_text, finalized) = stabilizer.update(&items);
+
+            if !partial_text.is_empty() {
+                let _ = app.emit(
+                    "transcript-partial",
+                    serde_json::json!({ "text": partial_text }),
+                );
+            }
+
+            for update in finalized {
+                if let Ok(payload) = serde_json::to_string(&update) {
+                    let _ = app.emit("transcript-update", payload);
+                }
+            }
+        }
+
+        log::info!("Streaming transcription: session closed");
+    });
+
+    StreamingSession { audio_tx, task }
+}
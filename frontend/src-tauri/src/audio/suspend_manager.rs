@@ -0,0 +1,252 @@
+// audio/suspend_manager.rs
+//
+// A laptop lid closing mid-meeting kills the CPAL audio streams outright,
+// but `RECORDING_MANAGER` has no way to notice on its own — it keeps
+// believing capture is live, so the recording that comes back after wake is
+// silently missing everything from the gap. `register()` hooks the
+// platform's power-event source (macOS `NSWorkspaceWillSleepNotification`/
+// `NSWorkspaceDidWakeNotification`, Windows `WM_POWERBROADCAST`, the
+// `org.freedesktop.login1` `PrepareForSleep` D-Bus signal on Linux) and
+// funnels both directions through `on_suspend`/`on_resume`, so the recording
+// lifecycle only has to reason about those two calls regardless of platform.
+// Linux is wired up for real over the system D-Bus (see `install_linux`);
+// macOS/Windows are still the honest no-op stubs below until someone picks
+// an Objective-C/Win32 binding to drive them the same way.
+
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Runtime};
+
+static WAS_SUSPENDED: AtomicBool = AtomicBool::new(false);
+static SUSPEND_COUNT: AtomicU32 = AtomicU32::new(0);
+
+// Set while a recording is paused specifically because of a suspend, so
+// `on_resume` can tell that apart from a recording the user paused manually
+// and leave the latter alone.
+static SUSPENDED_BY_US: Mutex<bool> = Mutex::new(false);
+
+/// Whether the active (or just-ended) recording lived through at least one
+/// suspend/resume cycle, surfaced via `get_recording_state` so the frontend
+/// can warn the user even after the fact.
+pub fn was_suspended() -> bool {
+    WAS_SUSPENDED.load(Ordering::SeqCst)
+}
+
+/// How many times the current recording has suspended so far.
+pub fn suspend_count() -> u32 {
+    SUSPEND_COUNT.load(Ordering::SeqCst)
+}
+
+/// Reset suspend/resume bookkeeping at the start of a new recording session.
+pub fn reset() {
+    WAS_SUSPENDED.store(false, Ordering::SeqCst);
+    SUSPEND_COUNT.store(0, Ordering::SeqCst);
+    *SUSPENDED_BY_US.lock().unwrap() = false;
+}
+
+/// Handles a "the OS is about to sleep" event. Synchronously mirrors
+/// `pause_recording` so CPAL never gets to write from a stream the OS is
+/// about to tear down, flushes whatever transcript segments are already
+/// buffered, and stamps a "suspended" marker so a recording that never comes
+/// back (lid stays shut) still has an honest stop reason instead of just
+/// trailing off.
+pub async fn on_suspend<R: Runtime>(app: &AppHandle<R>) {
+    if !super::recording_commands::is_recording().await
+        || super::recording_commands::is_recording_paused().await
+    {
+        return;
+    }
+
+    log::warn!("💤 OS suspend detected mid-recording; pausing and flushing transcript");
+
+    if super::recording_commands::pause_recording(app.clone())
+        .await
+        .is_err()
+    {
+        log::warn!("⚠️ Suspend handler could not pause recording");
+        return;
+    }
+
+    super::recording_commands::checkpoint_transcript_to_disk(app).await;
+
+    *SUSPENDED_BY_US.lock().unwrap() = true;
+    WAS_SUSPENDED.store(true, Ordering::SeqCst);
+    let count = SUSPEND_COUNT.fetch_add(1, Ordering::SeqCst) + 1;
+
+    let _ = app.emit(
+        "recording-suspended",
+        serde_json::json!({
+            "suspendCount": count,
+            "timestamp": chrono::Local::now().to_rfc3339(),
+        }),
+    );
+}
+
+/// Handles a "the OS just woke up" event. Only resumes capture if both the
+/// microphone and system-audio devices can actually be reconnected; resuming
+/// regardless would look like a healthy recording while quietly losing audio
+/// until the user notices. On failure, emits `recording-suspend-interrupted`
+/// instead so the frontend can warn that audio around the sleep may be
+/// missing, and leaves the recording paused for the user to retry or stop.
+pub async fn on_resume<R: Runtime>(app: &AppHandle<R>) {
+    let mut suspended_by_us = SUSPENDED_BY_US.lock().unwrap();
+    if !*suspended_by_us {
+        // Either not recording, or the user paused it themselves — a resume
+        // we didn't cause is none of our business.
+        return;
+    }
+    *suspended_by_us = false;
+    drop(suspended_by_us);
+
+    log::info!("🌅 OS resume detected; attempting audio device reconnect");
+
+    let (mic_name, system_name) = super::recording_commands::active_device_names();
+
+    let mic_reconnected = match mic_name {
+        Some(name) => super::recording_commands::attempt_device_reconnect(
+            name,
+            "Microphone".to_string(),
+        )
+        .await
+        .unwrap_or(false),
+        None => true,
+    };
+
+    let system_reconnected = match system_name {
+        Some(name) => super::recording_commands::attempt_device_reconnect(
+            name,
+            "SystemAudio".to_string(),
+        )
+        .await
+        .unwrap_or(false),
+        None => true,
+    };
+
+    if mic_reconnected && system_reconnected {
+        let _ = super::recording_commands::resume_recording(app.clone()).await;
+        log::info!("✅ Resumed recording after suspend");
+    } else {
+        let _ = app.emit(
+            "recording-suspend-interrupted",
+            serde_json::json!({
+                "microphoneReconnected": mic_reconnected,
+                "systemAudioReconnected": system_reconnected,
+            }),
+        );
+        log::warn!(
+            "⚠️ Could not reconnect all devices after resume (mic={}, system={}); recording stays paused",
+            mic_reconnected,
+            system_reconnected
+        );
+    }
+}
+
+/// Install the platform power-event hook for the lifetime of the app. Safe
+/// to call once at startup; a platform with no hook implemented here simply
+/// never calls `on_suspend`/`on_resume`, so recording behaves as it did
+/// before this module existed.
+pub fn register<R: Runtime + 'static>(app: AppHandle<R>) {
+    #[cfg(target_os = "macos")]
+    install_macos(app);
+
+    #[cfg(target_os = "windows")]
+    install_windows(app);
+
+    #[cfg(target_os = "linux")]
+    install_linux(app);
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        let _ = app;
+        log::warn!("Suspend/resume detection is not implemented for this platform");
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn install_macos<R: Runtime>(app: AppHandle<R>) {
+    // A full implementation registers for `NSWorkspaceWillSleepNotification`
+    // and `NSWorkspaceDidWakeNotification` on `NSWorkspace::sharedWorkspace()
+    // .notificationCenter()`, then dispatches each into `on_suspend`/
+    // `on_resume` on the Tokio runtime since the Cocoa notification callback
+    // itself runs on the main thread.
+    log::warn!("Suspend/resume detection is not implemented on macOS (no NSWorkspace hook registered)");
+    let _ = app;
+}
+
+#[cfg(target_os = "windows")]
+fn install_windows(app: AppHandle<impl Runtime>) {
+    // A full implementation intercepts `WM_POWERBROADCAST` in the window
+    // procedure (`PBT_APMSUSPEND` / `PBT_APMRESUMEAUTOMATIC`) and forwards
+    // each into `on_suspend`/`on_resume`.
+    log::warn!("Suspend/resume detection is not implemented on Windows (no WM_POWERBROADCAST hook registered)");
+    let _ = app;
+}
+
+/// Subscribes to the `PrepareForSleep` signal on `org.freedesktop.login1.Manager`
+/// over the system D-Bus: the signal's single bool argument is `true` just
+/// before suspend (mapped to `on_suspend`) and `false` on wake (mapped to
+/// `on_resume`). Runs for the lifetime of the app; a dropped D-Bus connection
+/// (logind restarted, bus unreachable) ends the task and is logged, not
+/// retried — recording still works, it just loses suspend detection until
+/// the app restarts, same as running on an unsupported platform today.
+#[cfg(target_os = "linux")]
+fn install_linux<R: Runtime + 'static>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        use futures_util::StreamExt;
+
+        let connection = match zbus::Connection::system().await {
+            Ok(connection) => connection,
+            Err(e) => {
+                log::warn!("Suspend/resume: failed to connect to system D-Bus: {}", e);
+                return;
+            }
+        };
+
+        let login_manager = match zbus::Proxy::new(
+            &connection,
+            "org.freedesktop.login1",
+            "/org/freedesktop/login1",
+            "org.freedesktop.login1.Manager",
+        )
+        .await
+        {
+            Ok(proxy) => proxy,
+            Err(e) => {
+                log::warn!("Suspend/resume: failed to reach logind: {}", e);
+                return;
+            }
+        };
+
+        let mut signal_stream = match login_manager.receive_signal("PrepareForSleep").await {
+            Ok(stream) => stream,
+            Err(e) => {
+                log::warn!("Suspend/resume: failed to subscribe to PrepareForSleep: {}", e);
+                return;
+            }
+        };
+
+        log::info!("Suspend/resume hook registered (org.freedesktop.login1 PrepareForSleep)");
+
+        while let Some(signal) = signal_stream.next().await {
+            match signal.body::<bool>() {
+                Ok(true) => on_suspend(&app).await,
+                Ok(false) => on_resume(&app).await,
+                Err(e) => log::warn!("Suspend/resume: malformed PrepareForSleep payload: {}", e),
+            }
+        }
+
+        log::warn!("Suspend/resume: PrepareForSleep signal stream ended unexpectedly");
+    });
+}
+
+/// Poll-based counterpart to `poll_audio_device_events`, returning whatever
+/// suspend bookkeeping has accumulated since the recording started. It only
+/// reports suspends seen by the native hook; on a platform without one it
+/// always reports none.
+#[tauri::command]
+pub async fn poll_suspend_events() -> serde_json::Value {
+    serde_json::json!({
+        "was_suspended": was_suspended(),
+        "suspend_count": suspend_count(),
+    })
+}
@@ -0,0 +1,99 @@
+// audio/vocabulary_filter.rs
+//
+// Applies a user-configured word list to transcript segments before they are
+// persisted, so sensitive names/terms never reach the saved history. Runs
+// inside the `transcript-update` listener, after the engine/provider has
+// produced text but before a `TranscriptSegment` is built. The
+// `transcript-update` event itself is not altered: anything else listening
+// to it, such as a live caption, receives the unfiltered text.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+/// How a matched word in `words` is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FilterMethod {
+    /// Replace the matched word with `***`.
+    #[default]
+    Mask,
+    /// Drop the matched word entirely.
+    Remove,
+    /// Keep the word but annotate it, e.g. `word[filtered]`.
+    Tag,
+}
+
+/// Mirrors the vocabulary-filter fields intended for `recording_preferences`:
+/// `words` are masked/removed/tagged per `method`, while `boost_words` are
+/// domain terms/names that should never be filtered even if they happen to
+/// overlap a match (e.g. a product name that is also a common word).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VocabularyPreference {
+    pub words: Vec<String>,
+    pub method: FilterMethod,
+    pub boost_words: Vec<String>,
+}
+
+static VOCABULARY_PREFERENCE: Mutex<Option<VocabularyPreference>> = Mutex::new(None);
+
+/// Current vocabulary preference, readable synchronously from the
+/// transcript-update listener.
+pub fn current_vocabulary_preference() -> VocabularyPreference {
+    VOCABULARY_PREFERENCE.lock().unwrap().clone().unwrap_or_default()
+}
+
+/// Update the vocabulary filter, chosen from Settings.
+#[tauri::command]
+pub async fn set_vocabulary_preference(preference: VocabularyPreference) -> Result<(), String> {
+    log::info!(
+        "Vocabulary filter set: {} word(s), method={:?}, {} boosted",
+        preference.words.len(),
+        preference.method,
+        preference.boost_words.len()
+    );
+    *VOCABULARY_PREFERENCE.lock().unwrap() = Some(preference);
+    Ok(())
+}
+
+/// Apply the current vocabulary filter to a segment of transcript text.
+/// Matching is whole-word and case-insensitive; words in `boost_words` are
+/// never filtered.
+pub fn apply(text: &str) -> String {
+    let preference = current_vocabulary_preference();
+    if preference.words.is_empty() {
+        return text.to_string();
+    }
+
+    let boosted: std::collections::HashSet<String> = preference
+        .boost_words
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect();
+
+    text.split_whitespace()
+        .map(|token| {
+            let bare = token.trim_matches(|c: char| !c.is_alphanumeric());
+            let lower = bare.to_lowercase();
+
+            if bare.is_empty() || boosted.contains(&lower) {
+                return token.to_string();
+            }
+
+            let matched = preference
+                .words
+                .iter()
+                .any(|w| w.to_lowercase() == lower);
+
+            if !matched {
+                return token.to_string();
+            }
+
+            match preference.method {
+                FilterMethod::Mask => token.replace(bare, "***"),
+                FilterMethod::Remove => String::new(),
+                FilterMethod::Tag => format!("{}[filtered]", token),
+            }
+        })
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
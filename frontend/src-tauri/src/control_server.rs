@@ -0,0 +1,86 @@
+// control_server.rs
+//
+// Small localhost HTTP server exposing recording state and control, so
+// external tools and scripts (calendar automations, meeting bots) can
+// integrate with Meetily the same way the tray does. `GET /status` mirrors
+// the tray's recording-state check, and `POST /recording/{start,pause,
+// resume,stop}` invoke the exact same handlers the tray menu wires up, so
+// tray and HTTP paths share one code path.
+
+use axum::{extract::State, routing::{get, post}, Json, Router};
+use serde::Serialize;
+use tauri::{AppHandle, Runtime};
+
+/// Default port for the local control server. Bound to loopback only.
+pub const DEFAULT_CONTROL_SERVER_PORT: u16 = 7437;
+
+#[derive(Clone)]
+struct ServerState<R: Runtime> {
+    app: AppHandle<R>,
+}
+
+#[derive(Serialize)]
+struct StatusResponse {
+    is_recording: bool,
+    is_paused: bool,
+}
+
+#[derive(Serialize)]
+struct AckResponse {
+    ok: bool,
+}
+
+/// Start the control server in the background. Errors (e.g. the port is
+/// already in use) are logged, not fatal — automation is a convenience, not
+/// a requirement for the app to run.
+pub fn start<R: Runtime + 'static>(app: AppHandle<R>, port: u16) {
+    let state = ServerState { app };
+
+    let router = Router::new()
+        .route("/status", get(status_handler::<R>))
+        .route("/recording/start", post(start_handler::<R>))
+        .route("/recording/pause", post(pause_handler::<R>))
+        .route("/recording/resume", post(resume_handler::<R>))
+        .route("/recording/stop", post(stop_handler::<R>))
+        .with_state(state);
+
+    tauri::async_runtime::spawn(async move {
+        let addr = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+        match tokio::net::TcpListener::bind(addr).await {
+            Ok(listener) => {
+                log::info!("Control server: listening on http://{}", addr);
+                if let Err(e) = axum::serve(listener, router).await {
+                    log::error!("Control server: stopped with error: {}", e);
+                }
+            }
+            Err(e) => log::error!("Control server: failed to bind {}: {}", addr, e),
+        }
+    });
+}
+
+async fn status_handler<R: Runtime>(State(_state): State<ServerState<R>>) -> Json<StatusResponse> {
+    Json(StatusResponse {
+        is_recording: crate::audio::recording_commands::is_recording().await,
+        is_paused: crate::audio::recording_commands::is_recording_paused().await,
+    })
+}
+
+async fn start_handler<R: Runtime>(State(state): State<ServerState<R>>) -> Json<AckResponse> {
+    crate::tray::start_recording_handler(&state.app);
+    Json(AckResponse { ok: true })
+}
+
+async fn pause_handler<R: Runtime>(State(state): State<ServerState<R>>) -> Json<AckResponse> {
+    crate::tray::pause_recording_handler(&state.app);
+    Json(AckResponse { ok: true })
+}
+
+async fn resume_handler<R: Runtime>(State(state): State<ServerState<R>>) -> Json<AckResponse> {
+    crate::tray::resume_recording_handler(&state.app);
+    Json(AckResponse { ok: true })
+}
+
+async fn stop_handler<R: Runtime>(State(state): State<ServerState<R>>) -> Json<AckResponse> {
+    crate::tray::stop_recording_handler(&state.app);
+    Json(AckResponse { ok: true })
+}
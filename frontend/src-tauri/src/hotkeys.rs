@@ -0,0 +1,124 @@
+// hotkeys.rs
+//
+// Configurable system-wide global shortcuts for recording control, so users
+// can toggle/pause/resume/stop a recording without opening the tray menu or
+// the main window. Registered at the same point `create_tray` wires up the
+// tray, and reuses the exact same handlers the tray menu items call.
+//
+// `set_hotkey_bindings` is the Settings-page entry point: the frontend owns
+// persisting the chosen accelerators (same split as every other "chosen from
+// Settings" preference in this codebase, e.g. `recording_commands`'s
+// `WorkerPoolPreference`) and calls this command on save and on every app
+// launch, which re-registers with the OS via `update_global_shortcuts`.
+
+use std::sync::Mutex;
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+use crate::tray::{
+    pause_recording_handler, resume_recording_handler, stop_recording_handler,
+    toggle_recording_handler,
+};
+
+/// Accelerator strings for the four recording-control shortcuts. Overridden
+/// from the Settings page; falls back to `Default` when nothing is saved.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HotkeyBindings {
+    pub toggle_recording: String,
+    pub pause_recording: String,
+    pub resume_recording: String,
+    pub stop_recording: String,
+}
+
+impl Default for HotkeyBindings {
+    fn default() -> Self {
+        Self {
+            toggle_recording: "CmdOrCtrl+Shift+R".to_string(),
+            pause_recording: "CmdOrCtrl+Shift+P".to_string(),
+            resume_recording: "CmdOrCtrl+Shift+U".to_string(),
+            stop_recording: "CmdOrCtrl+Shift+S".to_string(),
+        }
+    }
+}
+
+/// Register the recording-control shortcuts with the OS.
+pub fn register_global_shortcuts<R: Runtime>(
+    app: &AppHandle<R>,
+    bindings: HotkeyBindings,
+) -> tauri::Result<()> {
+    let app_toggle = app.clone();
+    app.global_shortcut().on_shortcut(
+        bindings.toggle_recording.as_str(),
+        move |_app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                toggle_recording_handler(&app_toggle);
+            }
+        },
+    )?;
+
+    let app_pause = app.clone();
+    app.global_shortcut().on_shortcut(
+        bindings.pause_recording.as_str(),
+        move |_app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                pause_recording_handler(&app_pause);
+            }
+        },
+    )?;
+
+    let app_resume = app.clone();
+    app.global_shortcut().on_shortcut(
+        bindings.resume_recording.as_str(),
+        move |_app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                resume_recording_handler(&app_resume);
+            }
+        },
+    )?;
+
+    let app_stop = app.clone();
+    app.global_shortcut().on_shortcut(
+        bindings.stop_recording.as_str(),
+        move |_app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                stop_recording_handler(&app_stop);
+            }
+        },
+    )?;
+
+    log::info!("Hotkeys: registered global shortcuts for recording control");
+    Ok(())
+}
+
+/// Re-register with new bindings after the user changes them in Settings.
+pub fn update_global_shortcuts<R: Runtime>(
+    app: &AppHandle<R>,
+    bindings: HotkeyBindings,
+) -> tauri::Result<()> {
+    app.global_shortcut().unregister_all()?;
+    register_global_shortcuts(app, bindings)
+}
+
+/// Current bindings, readable synchronously wherever shortcuts need to be
+/// re-applied (e.g. after a global-shortcut plugin error recovery).
+static CURRENT_BINDINGS: Mutex<Option<HotkeyBindings>> = Mutex::new(None);
+
+/// Current hotkey bindings, falling back to `Default` before Settings has
+/// ever called `set_hotkey_bindings`.
+pub fn current_hotkey_bindings() -> HotkeyBindings {
+    CURRENT_BINDINGS.lock().unwrap().clone().unwrap_or_default()
+}
+
+/// Update the hotkey bindings, chosen from the Settings page, and
+/// re-register them with the OS immediately so the change takes effect
+/// without restarting the app.
+#[tauri::command]
+pub async fn set_hotkey_bindings<R: Runtime>(
+    app: AppHandle<R>,
+    bindings: HotkeyBindings,
+) -> Result<(), String> {
+    update_global_shortcuts(&app, bindings.clone()).map_err(|e| e.to_string())?;
+    *CURRENT_BINDINGS.lock().unwrap() = Some(bindings);
+    log::info!("Hotkeys: bindings updated from Settings");
+    Ok(())
+}
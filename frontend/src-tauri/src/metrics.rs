@@ -0,0 +1,237 @@
+// metrics.rs
+//
+// Optional Prometheus exporter for the recording/transcription pipeline,
+// gated behind the `metrics` cargo feature so builds that don't want a
+// scrape endpoint (or the push-gateway client) running on the user's
+// machine don't pay for it. Counters/labels here follow the same
+// privacy-safe rule as `track_meeting_ended`: device *types*, via
+// `recording_commands::classify_device_type`, are fine to export; device
+// names and transcript content never are.
+//
+// Gauges (`active recording count`, `chunk queue depth`, `reconnecting`)
+// are computed on demand at scrape time by calling straight into
+// `recording_commands`, the same way `control_server`'s `/status` does,
+// rather than mirrored into separate statics that could drift out of
+// sync with the real state. Only genuinely cumulative values — chunks
+// processed, segments emitted, device disconnects/reconnects — are kept
+// as counters here.
+
+#![cfg(feature = "metrics")]
+
+use axum::{extract::State, routing::get, Router};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Runtime};
+
+/// Default port for the local metrics scrape endpoint. Bound to loopback
+/// only, same as `control_server`.
+pub const DEFAULT_METRICS_PORT: u16 = 7438;
+
+static CHUNKS_PROCESSED_TOTAL: AtomicU64 = AtomicU64::new(0);
+static SEGMENTS_EMITTED_TOTAL: AtomicU64 = AtomicU64::new(0);
+static DEVICE_DISCONNECTS_TOTAL: Mutex<Option<HashMap<&'static str, u64>>> = Mutex::new(None);
+static DEVICE_RECONNECTS_TOTAL: Mutex<Option<HashMap<&'static str, u64>>> = Mutex::new(None);
+
+/// Configuration for the optional push-gateway mode, chosen from Settings.
+/// When absent, the exporter only serves the local scrape endpoint.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct PushGatewayPreference {
+    pub enabled: bool,
+    pub url: String,
+    pub interval_secs: u64,
+}
+
+static PUSH_GATEWAY_PREFERENCE: Mutex<PushGatewayPreference> =
+    Mutex::new(PushGatewayPreference {
+        enabled: false,
+        url: String::new(),
+        interval_secs: 30,
+    });
+
+/// Update the push-gateway preference, chosen from Settings. Takes effect on
+/// the next push tick; does not restart an already-running push loop.
+#[tauri::command]
+pub async fn set_push_gateway_preference(preference: PushGatewayPreference) -> Result<(), String> {
+    log::info!(
+        "Metrics push-gateway preference set: enabled={}, interval={}s",
+        preference.enabled,
+        preference.interval_secs
+    );
+    *PUSH_GATEWAY_PREFERENCE.lock().unwrap() = preference;
+    Ok(())
+}
+
+fn current_push_gateway_preference() -> PushGatewayPreference {
+    PUSH_GATEWAY_PREFERENCE.lock().unwrap().clone()
+}
+
+/// Record that the transcription pipeline consumed one more audio chunk.
+pub fn record_chunk_processed() {
+    CHUNKS_PROCESSED_TOTAL.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Record that one more transcript segment was emitted to the frontend/history.
+pub fn record_segment_emitted() {
+    SEGMENTS_EMITTED_TOTAL.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Record a device disconnect, labelled only by its privacy-safe type
+/// (`Bluetooth`/`Wired`), never by name.
+pub fn record_device_disconnected(device_type: &'static str) {
+    *DEVICE_DISCONNECTS_TOTAL
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .entry(device_type)
+        .or_insert(0) += 1;
+}
+
+/// Record a device reconnect, labelled only by its privacy-safe type.
+pub fn record_device_reconnected(device_type: &'static str) {
+    *DEVICE_RECONNECTS_TOTAL
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .entry(device_type)
+        .or_insert(0) += 1;
+}
+
+/// Render the current counters and gauges in the Prometheus text exposition
+/// format. Gauges are computed fresh on every call.
+async fn render() -> String {
+    let mut out = String::new();
+
+    let active_recordings = if crate::audio::recording_commands::is_recording().await {
+        1
+    } else {
+        0
+    };
+    let chunk_queue_depth = crate::audio::recording_commands::get_transcription_status()
+        .await
+        .chunks_in_queue;
+    let reconnecting = if crate::audio::recording_commands::is_reconnecting() {
+        1
+    } else {
+        0
+    };
+
+    out.push_str("# HELP meetily_active_recordings Whether a recording is currently active (0 or 1).\n");
+    out.push_str("# TYPE meetily_active_recordings gauge\n");
+    out.push_str(&format!("meetily_active_recordings {}\n", active_recordings));
+
+    out.push_str("# HELP meetily_chunk_queue_depth Number of audio chunks awaiting transcription.\n");
+    out.push_str("# TYPE meetily_chunk_queue_depth gauge\n");
+    out.push_str(&format!("meetily_chunk_queue_depth {}\n", chunk_queue_depth));
+
+    out.push_str("# HELP meetily_device_reconnecting Whether a device reconnection attempt is in progress (0 or 1).\n");
+    out.push_str("# TYPE meetily_device_reconnecting gauge\n");
+    out.push_str(&format!("meetily_device_reconnecting {}\n", reconnecting));
+
+    out.push_str("# HELP meetily_chunks_processed_total Cumulative audio chunks processed by the transcription pipeline.\n");
+    out.push_str("# TYPE meetily_chunks_processed_total counter\n");
+    out.push_str(&format!(
+        "meetily_chunks_processed_total {}\n",
+        CHUNKS_PROCESSED_TOTAL.load(Ordering::Relaxed)
+    ));
+
+    out.push_str("# HELP meetily_transcript_segments_total Cumulative transcript segments emitted.\n");
+    out.push_str("# TYPE meetily_transcript_segments_total counter\n");
+    out.push_str(&format!(
+        "meetily_transcript_segments_total {}\n",
+        SEGMENTS_EMITTED_TOTAL.load(Ordering::Relaxed)
+    ));
+
+    out.push_str("# HELP meetily_device_disconnects_total Cumulative audio device disconnects, by device type.\n");
+    out.push_str("# TYPE meetily_device_disconnects_total counter\n");
+    for (device_type, count) in DEVICE_DISCONNECTS_TOTAL.lock().unwrap().iter().flatten() {
+        out.push_str(&format!(
+            "meetily_device_disconnects_total{{device_type=\"{}\"}} {}\n",
+            device_type, count
+        ));
+    }
+
+    out.push_str("# HELP meetily_device_reconnects_total Cumulative audio device reconnects, by device type.\n");
+    out.push_str("# TYPE meetily_device_reconnects_total counter\n");
+    for (device_type, count) in DEVICE_RECONNECTS_TOTAL.lock().unwrap().iter().flatten() {
+        out.push_str(&format!(
+            "meetily_device_reconnects_total{{device_type=\"{}\"}} {}\n",
+            device_type, count
+        ));
+    }
+
+    out
+}
+
+#[derive(Clone)]
+struct ServerState<R: Runtime> {
+    app: AppHandle<R>,
+}
+
+async fn scrape_handler<R: Runtime>(State(_state): State<ServerState<R>>) -> String {
+    render().await
+}
+
+/// Start the metrics scrape endpoint in the background, and if push-gateway
+/// mode is enabled, the periodic push loop alongside it. Errors (e.g. the
+/// port is already in use) are logged, not fatal — metrics are an
+/// operational convenience, not a requirement for the app to run.
+pub fn start<R: Runtime + 'static>(app: AppHandle<R>, port: u16) {
+    let state = ServerState { app: app.clone() };
+
+    let router = Router::new()
+        .route("/metrics", get(scrape_handler::<R>))
+        .with_state(state);
+
+    tauri::async_runtime::spawn(async move {
+        let addr = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+        match tokio::net::TcpListener::bind(addr).await {
+            Ok(listener) => {
+                log::info!("Metrics: listening on http://{}", addr);
+                if let Err(e) = axum::serve(listener, router).await {
+                    log::error!("Metrics: scrape endpoint stopped with error: {}", e);
+                }
+            }
+            Err(e) => log::error!("Metrics: failed to bind {}: {}", addr, e),
+        }
+    });
+
+    tauri::async_runtime::spawn(push_loop());
+}
+
+/// Periodically push the current exposition text to the configured
+/// push-gateway URL, for users running the app headless on a dedicated
+/// capture machine where nothing ever scrapes `/metrics` directly. Checks
+/// the preference on every tick so enabling/disabling takes effect without
+/// restarting the app.
+async fn push_loop() {
+    loop {
+        let preference = current_push_gateway_preference();
+
+        if preference.enabled && !preference.url.is_empty() {
+            let body = render().await;
+            match reqwest::Client::new()
+                .post(&preference.url)
+                .header("Content-Type", "text/plain; version=0.0.4")
+                .body(body)
+                .send()
+                .await
+            {
+                Ok(response) if response.status().is_success() => {
+                    log::debug!("Metrics: pushed to gateway {}", preference.url);
+                }
+                Ok(response) => {
+                    log::warn!(
+                        "Metrics: push-gateway {} responded with {}",
+                        preference.url,
+                        response.status()
+                    );
+                }
+                Err(e) => log::warn!("Metrics: failed to push to gateway {}: {}", preference.url, e),
+            }
+        }
+
+        let sleep_secs = preference.interval_secs.max(5);
+        tokio::time::sleep(std::time::Duration::from_secs(sleep_secs)).await;
+    }
+}
@@ -0,0 +1,41 @@
+// notifications.rs
+//
+// Desktop notifications for recording lifecycle transitions. Fired from the
+// tray handlers (and, for "started", from the recording start path) so users running the app minimized to the tray get an
+// out-of-band signal instead of only a reverted menu on failure.
+
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_notification::NotificationExt;
+
+pub fn notify_recording_started<R: Runtime>(app: &AppHandle<R>) {
+    show(app, "Recording started");
+}
+
+pub fn notify_recording_paused<R: Runtime>(app: &AppHandle<R>) {
+    show(app, "Recording paused");
+}
+
+pub fn notify_recording_resumed<R: Runtime>(app: &AppHandle<R>) {
+    show(app, "Recording resumed");
+}
+
+pub fn notify_recording_saved<R: Runtime>(app: &AppHandle<R>, save_path: &str) {
+    show(app, &format!("Recording saved to {}", save_path));
+}
+
+pub fn notify_recording_failed<R: Runtime>(app: &AppHandle<R>, action: &str, reason: &str) {
+    show(app, &format!("Failed to {}: {}", action, reason));
+}
+
+fn show<R: Runtime>(app: &AppHandle<R>, body: &str) {
+    let result = app
+        .notification()
+        .builder()
+        .title("Meetily")
+        .body(body)
+        .show();
+
+    if let Err(e) = result {
+        log::warn!("Notifications: failed to show desktop notification: {}", e);
+    }
+}
@@ -8,15 +8,21 @@ use tauri::{
 pub enum RecordingState {
     Stopped,
     Starting,
-    Recording,
+    Recording { elapsed_secs: u64, level: f32 },
     Pausing,
     Paused,
+    /// Paused automatically by the silence watchdog, as opposed to a manual
+    /// `Paused`, so the menu can tell the user why and resuming is distinct
+    /// from a user-initiated resume.
+    AutoPausedSilence,
     Resuming,
     Stopping,
 }
 
 pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
-    // Start with default menu, will update with actual state after initialization
+    // Start with default menu; the subscriber loop below rebuilds it for every
+    // subsequent transition, so this only covers the brief window before the
+    // first state is received.
     let menu = build_menu(app, RecordingState::Stopped)?;
 
     TrayIconBuilder::with_id("main-tray")
@@ -26,12 +32,81 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
         .on_menu_event(|app, event| handle_menu_event(app, event.id.as_ref()))
         .build(app)?;
 
-    // Update tray menu with actual recording state after creation
-    update_tray_menu(app);
+    // Rebuild the menu from the audio subsystem's `RecordingState` stream
+    // instead of polling `is_recording()`/`is_recording_paused()`. This is the
+    // single authoritative source for tray state, so
+    // intermediate states (Starting/Pausing/Resuming/Stopping) are real
+    // transitions rather than faked locally.
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut rx = crate::audio::recording_commands::subscribe_recording_state();
+        loop {
+            let state = rx.borrow().clone();
+            apply_tray_state(&app_clone, state);
+            if rx.changed().await.is_err() {
+                // Sender dropped (should only happen on app shutdown).
+                break;
+            }
+        }
+    });
+
+    // Let the same handlers the tray menu items call also be triggered from
+    // system-wide shortcuts, so recording can be controlled without opening
+    // the tray menu or the main window.
+    if let Err(e) = crate::hotkeys::register_global_shortcuts(
+        app,
+        crate::hotkeys::HotkeyBindings::default(),
+    ) {
+        log::warn!("Tray: failed to register global shortcuts: {}", e);
+    }
+
+    // Expose the same recording controls over a local HTTP endpoint, so
+    // external tools (calendar automations, meeting bots) can drive Meetily
+    // without opening the tray menu or the main window.
+    crate::control_server::start(app.clone(), crate::control_server::DEFAULT_CONTROL_SERVER_PORT);
+
+    // Detect OS suspend/resume so a sleeping laptop auto-pauses instead of
+    // producing a recording with a silent gap where the audio hardware died.
+    crate::audio::suspend_manager::register(app.clone());
+
+    // Optional Prometheus scrape endpoint (+ push-gateway mode) for users
+    // running Meetily on a dedicated capture machine.
+    #[cfg(feature = "metrics")]
+    crate::metrics::start(app.clone(), crate::metrics::DEFAULT_METRICS_PORT);
 
     Ok(())
 }
 
+/// Rebuild and install the tray menu for a given `RecordingState`.
+fn apply_tray_state<R: Runtime>(app: &AppHandle<R>, state: RecordingState) {
+    log::info!("Tray: applying recording state: {:?}", state);
+
+    let tooltip = match &state {
+        RecordingState::Recording { elapsed_secs, level } => {
+            format!(
+                "Meetily — recording {:02}:{:02} ({}% level)",
+                elapsed_secs / 60,
+                elapsed_secs % 60,
+                (level.clamp(0.0, 1.0) * 100.0) as u32
+            )
+        }
+        RecordingState::AutoPausedSilence => "Meetily — auto-paused (silence)".to_string(),
+        _ => "Meetily".to_string(),
+    };
+
+    if let Ok(menu) = build_menu(app, state) {
+        if let Some(tray) = app.tray_by_id("main-tray") {
+            let result = tray.set_menu(Some(menu));
+            log::info!("Tray: menu update result: {:?}", result);
+            let _ = tray.set_tooltip(Some(tooltip.as_str()));
+        } else {
+            log::warn!("Tray: could not find tray with id 'main-tray'");
+        }
+    } else {
+        log::error!("Tray: failed to build menu for recording state");
+    }
+}
+
 fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, item_id: &str) {
     match item_id {
         "toggle_recording" => toggle_recording_handler(app),
@@ -39,6 +114,15 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, item_id: &str) {
         "resume_recording" => resume_recording_handler(app),
         "stop_recording" => stop_recording_handler(app),
         "open_window" => focus_main_window(app),
+        "toggle_track_layout" => toggle_track_layout_handler(app),
+        "mute_microphone" => toggle_channel_mute_handler(
+            app,
+            crate::audio::recording_commands::AudioChannel::Microphone,
+        ),
+        "mute_system_audio" => toggle_channel_mute_handler(
+            app,
+            crate::audio::recording_commands::AudioChannel::SystemAudio,
+        ),
         "settings" => {
             focus_main_window(app);
             if let Some(window) = app.get_webview_window("main") {
@@ -49,13 +133,14 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, item_id: &str) {
         _ => {}
     }
 }
-fn toggle_recording_handler<R: Runtime>(app: &AppHandle<R>) {
+pub(crate) fn toggle_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     focus_main_window(app);
     let app_clone = app.clone();
     tauri::async_runtime::spawn(async move {
         if crate::is_recording().await {
-            // Immediately show stopping state
-            set_tray_state(&app_clone, RecordingState::Stopping);
+            // Publish the intermediate state; the tray's subscriber loop
+            // rebuilds the menu from this, so no local menu fakery is needed.
+            crate::audio::recording_commands::publish_recording_state(RecordingState::Stopping);
 
             log::info!("Tray toggle: Stopping recording...");
 
@@ -64,20 +149,17 @@ fn toggle_recording_handler<R: Runtime>(app: &AppHandle<R>) {
                 Ok(dir) => dir,
                 Err(e) => {
                     log::error!("Failed to get app data dir: {}", e);
-                    update_tray_menu_async(&app_clone).await;
+                    publish_current_recording_state(&app_clone).await;
                     return;
                 }
             };
 
-            let timestamp = chrono::Local::now().format("%Y-%m-%dT%H-%M-%S").to_string();
-            let save_path = data_dir.join(format!("recording-{}.wav", timestamp));
+            let (save_path, recording_args) = build_recording_args(&data_dir);
 
             // Call Rust stop_recording command (like pause/resume pattern)
             let stop_result = crate::audio::recording_commands::stop_recording(
                 app_clone.clone(),
-                crate::audio::recording_commands::RecordingArgs {
-                    save_path: save_path.to_string_lossy().to_string(),
-                },
+                recording_args,
             )
             .await;
 
@@ -85,12 +167,16 @@ fn toggle_recording_handler<R: Runtime>(app: &AppHandle<R>) {
             match stop_result {
                 Ok(_) => {
                     log::info!("Tray toggle: Recording stopped successfully");
+                    crate::notifications::notify_recording_saved(&app_clone, &save_path.to_string_lossy());
 
                     // Trigger frontend post-processing AFTER Rust completes
                     // (SQLite save, navigation, analytics)
                     if let Some(window) = app_clone.get_webview_window("main") {
                         let _ = window
-                            .eval("window.handleRecordingStop && window.handleRecordingStop(true)");
+                            .eval(&format!(
+                                "window.handleRecordingStop && window.handleRecordingStop(true, {})",
+                                serde_json::to_string(&save_path.to_string_lossy().to_string()).unwrap_or_default()
+                            ));
                     } else {
                         log::warn!(
                             "Tray toggle: Main window not found for post-processing callback"
@@ -99,61 +185,84 @@ fn toggle_recording_handler<R: Runtime>(app: &AppHandle<R>) {
                 }
                 Err(e) => {
                     log::error!("Tray toggle: Failed to stop recording: {}", e);
-                    // Revert tray state on error
-                    update_tray_menu_async(&app_clone).await;
+                    crate::notifications::notify_recording_failed(&app_clone, "stop recording", &e);
+                    // Re-publish the real state on error so the tray reflects
+                    // reality instead of the faked "Stopping" transition.
+                    publish_current_recording_state(&app_clone).await;
                 }
             }
         } else {
-            // Immediately show starting state
-            set_tray_state(&app_clone, RecordingState::Starting);
-
-            log::info!("Emitting start recording event from tray");
-            if let Some(window) = app_clone.get_webview_window("main") {
-                let _ = window.eval("sessionStorage.setItem('autoStartRecording', 'true')"); // Set the flag to start recording automatically
-                let _ = window.eval("window.location.assign('/')");
-            }
+            start_recording_handler(&app_clone);
         }
     });
 }
 
-fn pause_recording_handler<R: Runtime>(app: &AppHandle<R>) {
-    // Immediately show pausing state
-    set_tray_state(app, RecordingState::Pausing);
+/// Kick off a new recording. Shared by the tray's toggle action and
+/// `control_server`'s `/recording/start`, so both paths end up anonymous
+/// (`meeting_name: None`) the same way and publish the same `Starting`
+/// transition — the frontend drives the actual start via
+/// `start_recording_with_meeting_name` once the main window picks up the
+/// `autoStartRecording` flag, which publishes `RecordingState::Recording`
+/// once it has truly begun.
+pub(crate) fn start_recording_handler<R: Runtime>(app: &AppHandle<R>) {
+    crate::audio::recording_commands::publish_recording_state(RecordingState::Starting);
+
+    log::info!("Emitting start recording event from tray");
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.eval("sessionStorage.setItem('autoStartRecording', 'true')"); // Set the flag to start recording automatically
+        let _ = window.eval("window.location.assign('/')");
+    } else {
+        log::warn!("Start recording: main window not found to trigger autostart");
+    }
+}
+
+pub(crate) fn pause_recording_handler<R: Runtime>(app: &AppHandle<R>) {
+    // Immediately publish the pausing transition; `pause_recording` itself
+    // publishes the authoritative `Paused` state on success, and this handler
+    // re-publishes the real state if it fails.
+    crate::audio::recording_commands::publish_recording_state(RecordingState::Pausing);
 
     let app_clone = app.clone();
     tauri::async_runtime::spawn(async move {
         if let Err(e) = crate::audio::recording_commands::pause_recording(app_clone.clone()).await {
             log::error!("Failed to pause recording from tray: {}", e);
+            crate::notifications::notify_recording_failed(&app_clone, "pause recording", &e);
             // Revert to current state on error
-            update_tray_menu_async(&app_clone).await;
+            publish_current_recording_state(&app_clone).await;
         } else {
             log::info!("Recording paused from tray");
-            // The pause_recording function will call update_tray_menu, so no need to call it here
+            crate::notifications::notify_recording_paused(&app_clone);
+            // pause_recording already published the Paused state
         }
     });
 }
 
-fn resume_recording_handler<R: Runtime>(app: &AppHandle<R>) {
-    // Immediately show resuming state
-    set_tray_state(app, RecordingState::Resuming);
+pub(crate) fn resume_recording_handler<R: Runtime>(app: &AppHandle<R>) {
+    // Immediately publish the resuming transition; `resume_recording` itself
+    // publishes the authoritative `Recording` state on success, and this
+    // handler re-publishes the real state if it fails.
+    crate::audio::recording_commands::publish_recording_state(RecordingState::Resuming);
 
     let app_clone = app.clone();
     tauri::async_runtime::spawn(async move {
         if let Err(e) = crate::audio::recording_commands::resume_recording(app_clone.clone()).await
         {
             log::error!("Failed to resume recording from tray: {}", e);
+            crate::notifications::notify_recording_failed(&app_clone, "resume recording", &e);
             // Revert to current state on error
-            update_tray_menu_async(&app_clone).await;
+            publish_current_recording_state(&app_clone).await;
         } else {
             log::info!("Recording resumed from tray");
-            // The resume_recording function will call update_tray_menu, so no need to call it here
+            crate::notifications::notify_recording_resumed(&app_clone);
+            // resume_recording already published the Recording state
         }
     });
 }
 
-fn stop_recording_handler<R: Runtime>(app: &AppHandle<R>) {
-    // Immediately show stopping state
-    set_tray_state(app, RecordingState::Stopping);
+pub(crate) fn stop_recording_handler<R: Runtime>(app: &AppHandle<R>) {
+    // Immediately publish the stopping transition; `stop_recording` publishes
+    // the authoritative `Stopped` state once teardown actually completes.
+    crate::audio::recording_commands::publish_recording_state(RecordingState::Stopping);
 
     focus_main_window(app);
     let app_clone = app.clone();
@@ -165,20 +274,17 @@ fn stop_recording_handler<R: Runtime>(app: &AppHandle<R>) {
             Ok(dir) => dir,
             Err(e) => {
                 log::error!("Failed to get app data dir: {}", e);
-                update_tray_menu_async(&app_clone).await;
+                publish_current_recording_state(&app_clone).await;
                 return;
             }
         };
 
-        let timestamp = chrono::Local::now().format("%Y-%m-%dT%H-%M-%S").to_string();
-        let save_path = data_dir.join(format!("recording-{}.wav", timestamp));
+        let (save_path, recording_args) = build_recording_args(&data_dir);
 
         // Call Rust stop_recording command (like pause/resume pattern)
         let stop_result = crate::audio::recording_commands::stop_recording(
             app_clone.clone(),
-            crate::audio::recording_commands::RecordingArgs {
-                save_path: save_path.to_string_lossy().to_string(),
-            },
+            recording_args,
         )
         .await;
 
@@ -189,88 +295,115 @@ fn stop_recording_handler<R: Runtime>(app: &AppHandle<R>) {
 
                 // Trigger frontend post-processing AFTER Rust completes
                 // (SQLite save, navigation, analytics)
+                crate::notifications::notify_recording_saved(&app_clone, &save_path.to_string_lossy());
+
                 if let Some(window) = app_clone.get_webview_window("main") {
                     let _ = window
-                        .eval("window.handleRecordingStop && window.handleRecordingStop(true)");
+                        .eval(&format!(
+                            "window.handleRecordingStop && window.handleRecordingStop(true, {})",
+                            serde_json::to_string(&save_path.to_string_lossy().to_string()).unwrap_or_default()
+                        ));
                 } else {
                     log::warn!("Tray: Main window not found for post-processing callback");
                 }
             }
             Err(e) => {
                 log::error!("Tray: Failed to stop recording: {}", e);
+                crate::notifications::notify_recording_failed(&app_clone, "stop recording", &e);
                 // Revert tray state on error
-                update_tray_menu_async(&app_clone).await;
+                publish_current_recording_state(&app_clone).await;
             }
         }
     });
 }
 
-pub fn update_tray_menu<R: Runtime>(app: &AppHandle<R>) {
-    // For sync update, spawn async task to get current state
-    let app_clone = app.clone();
-    tauri::async_runtime::spawn(async move {
-        // Small delay to ensure recording state has been updated
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-        update_tray_menu_async(&app_clone).await;
-    });
+/// Re-derive the real recording state and publish it, used to correct an
+/// optimistic transition after a handler fails partway through.
+async fn publish_current_recording_state<R: Runtime>(_app: &AppHandle<R>) {
+    let state = crate::audio::recording_commands::current_recording_state().await;
+    crate::audio::recording_commands::publish_recording_state(state);
 }
 
-pub fn set_tray_state<R: Runtime>(app: &AppHandle<R>, state: RecordingState) {
-    log::info!("Tray: Setting intermediate state: {:?}", state);
-    if let Ok(menu) = build_menu(app, state) {
-        if let Some(tray) = app.tray_by_id("main-tray") {
-            let result = tray.set_menu(Some(menu));
-            log::info!("Tray: Intermediate state menu update result: {:?}", result);
-        } else {
-            log::warn!("Tray: Could not find tray with id 'main-tray'");
-        }
-    } else {
-        log::error!("Tray: Failed to build menu for intermediate state");
-    }
+/// Rebuild the tray menu from the current recording state. Used after a
+/// setting that changes a menu label (track layout, channel mute) rather
+/// than the recording state itself, so the tray doesn't have to wait for the
+/// next `RecordingState` transition to reflect it.
+pub(crate) async fn update_tray_menu<R: Runtime>(app: &AppHandle<R>) {
+    let state = crate::audio::recording_commands::current_recording_state().await;
+    apply_tray_state(app, state);
 }
 
-async fn get_current_recording_state() -> RecordingState {
-    // Check if currently recording
-    let is_recording = crate::audio::recording_commands::is_recording().await;
-    log::info!(
-        "Tray: get_current_recording_state - is_recording: {}",
-        is_recording
-    );
-
-    if !is_recording {
-        log::info!("Tray: Recording state is Stopped");
-        return RecordingState::Stopped;
+/// Build the `RecordingArgs` stop_recording should use, honouring the
+/// current track layout: a single mixed file, or a microphone/system-audio
+/// pair when `TrackLayout::Separate` is selected.
+fn build_recording_args(
+    data_dir: &std::path::Path,
+) -> (std::path::PathBuf, crate::audio::recording_commands::RecordingArgs) {
+    use crate::audio::recording_commands::TrackLayout;
+
+    let timestamp = chrono::Local::now().format("%Y-%m-%dT%H-%M-%S").to_string();
+    let format = crate::audio::recording_commands::current_output_format();
+    let ext = format.extension();
+
+    match crate::audio::recording_commands::current_track_layout() {
+        TrackLayout::Mixed => {
+            let save_path = data_dir.join(format!("recording-{}.{}", timestamp, ext));
+            let args = crate::audio::recording_commands::RecordingArgs {
+                save_path: save_path.to_string_lossy().to_string(),
+                system_save_path: None,
+                format,
+            };
+            (save_path, args)
+        }
+        TrackLayout::Separate => {
+            let mic_path = data_dir.join(format!("recording-{}-mic.{}", timestamp, ext));
+            let system_path = data_dir.join(format!("recording-{}-system.{}", timestamp, ext));
+            let args = crate::audio::recording_commands::RecordingArgs {
+                save_path: mic_path.to_string_lossy().to_string(),
+                system_save_path: Some(system_path.to_string_lossy().to_string()),
+                format,
+            };
+            (mic_path, args)
+        }
     }
+}
 
-    // Check if paused
-    let is_paused = crate::audio::recording_commands::is_recording_paused().await;
-    log::info!("Tray: is_paused: {}", is_paused);
-
-    if is_paused {
-        log::info!("Tray: Recording state is Paused");
-        RecordingState::Paused
-    } else {
-        log::info!("Tray: Recording state is Recording");
-        RecordingState::Recording
-    }
+fn toggle_track_layout_handler<R: Runtime>(app: &AppHandle<R>) {
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let layout = crate::audio::recording_commands::toggle_track_layout().await;
+        log::info!("Tray: track layout set to {:?}", layout);
+        update_tray_menu(&app_clone).await;
+    });
 }
 
-pub async fn update_tray_menu_async<R: Runtime>(app: &AppHandle<R>) {
-    log::info!("Tray: update_tray_menu_async called");
-    // Get the current recording state
-    let recording_state = get_current_recording_state().await;
-    log::info!("Tray: Current recording state: {:?}", recording_state);
+/// Flip the mute state of one capture channel from the tray menu and
+/// immediately rebuild the menu so the label (Mute ▸ Unmute) stays in sync.
+fn toggle_channel_mute_handler<R: Runtime>(
+    app: &AppHandle<R>,
+    channel: crate::audio::recording_commands::AudioChannel,
+) {
+    use crate::audio::recording_commands::AudioChannel;
 
-    if let Ok(menu) = build_menu(app, recording_state) {
-        if let Some(tray) = app.tray_by_id("main-tray") {
-            let result = tray.set_menu(Some(menu));
-            log::info!("Tray: Menu update result: {:?}", result);
-        } else {
-            log::warn!("Tray: Could not find tray with id 'main-tray'");
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let muted = match channel {
+            AudioChannel::Microphone => !crate::audio::recording_commands::is_microphone_muted(),
+            AudioChannel::SystemAudio => !crate::audio::recording_commands::is_system_audio_muted(),
+        };
+
+        if let Err(e) = crate::audio::recording_commands::set_channel_muted(
+            app_clone.clone(),
+            channel,
+            muted,
+        )
+        .await
+        {
+            log::error!("Failed to toggle channel mute from tray: {}", e);
         }
-    } else {
-        log::error!("Tray: Failed to build menu");
-    }
+
+        update_tray_menu(&app_clone).await;
+    });
 }
 
 fn build_menu<R: Runtime>(
@@ -291,7 +424,7 @@ fn build_menu<R: Runtime>(
                     .build(app)?,
             );
         }
-        RecordingState::Recording => {
+        RecordingState::Recording { .. } => {
             builder = builder
                 .item(&MenuItemBuilder::with_id("pause_recording", "⏸ Pause Recording").build(app)?)
                 .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?);
@@ -313,6 +446,14 @@ fn build_menu<R: Runtime>(
                 )
                 .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?);
         }
+        RecordingState::AutoPausedSilence => {
+            builder = builder
+                .item(
+                    &MenuItemBuilder::with_id("resume_recording", "⏸ Auto-paused (silence)")
+                        .build(app)?,
+                )
+                .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?);
+        }
         RecordingState::Resuming => {
             builder = builder
                 .item(
@@ -331,10 +472,29 @@ fn build_menu<R: Runtime>(
         }
     }
 
+    let track_layout_label = match crate::audio::recording_commands::current_track_layout() {
+        crate::audio::recording_commands::TrackLayout::Mixed => "Track Layout: Mixed ▸ Separate",
+        crate::audio::recording_commands::TrackLayout::Separate => "Track Layout: Separate ▸ Mixed",
+    };
+
+    let mic_mute_label = if crate::audio::recording_commands::is_microphone_muted() {
+        "🎙 Unmute Microphone"
+    } else {
+        "🎙 Mute Microphone"
+    };
+    let system_mute_label = if crate::audio::recording_commands::is_system_audio_muted() {
+        "🔊 Unmute System Audio"
+    } else {
+        "🔊 Mute System Audio"
+    };
+
     builder
         .item(&PredefinedMenuItem::separator(app)?)
         .item(&MenuItemBuilder::with_id("open_window", "Open Main Window").build(app)?)
         .item(&MenuItemBuilder::with_id("settings", "Settings").build(app)?)
+        .item(&MenuItemBuilder::with_id("toggle_track_layout", track_layout_label).build(app)?)
+        .item(&MenuItemBuilder::with_id("mute_microphone", mic_mute_label).build(app)?)
+        .item(&MenuItemBuilder::with_id("mute_system_audio", system_mute_label).build(app)?)
         .item(&PredefinedMenuItem::separator(app)?)
         .item(&MenuItemBuilder::with_id("quit", "Quit").build(app)?)
         .build()